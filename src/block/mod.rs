@@ -4,7 +4,51 @@ use tui::{layout::Rect, style::Color};
 
 pub type Position = Vec<Cell>;
 
-#[derive(Debug, Clone)]
+/// Offsets tried in order when rotating clockwise out of each rotation state.
+/// Rotating counter-clockwise into a state uses the same offsets negated.
+pub type Kicks = [[Cell; 5]; 4];
+
+// Offsets tried in order when turning 180 degrees out of each rotation state.
+const HALF_TURN_KICKS: [[Cell; 6]; 4] = [
+    // 0 -> 2
+    [
+        Cell { x: 0, y: 0 },
+        Cell { x: 0, y: -1 },
+        Cell { x: 1, y: -1 },
+        Cell { x: -1, y: -1 },
+        Cell { x: 1, y: 0 },
+        Cell { x: -1, y: 0 },
+    ],
+    // R -> L
+    [
+        Cell { x: 0, y: 0 },
+        Cell { x: 1, y: 0 },
+        Cell { x: 1, y: -2 },
+        Cell { x: 1, y: -1 },
+        Cell { x: 0, y: -2 },
+        Cell { x: 0, y: -1 },
+    ],
+    // 2 -> 0
+    [
+        Cell { x: 0, y: 0 },
+        Cell { x: 0, y: 1 },
+        Cell { x: -1, y: 1 },
+        Cell { x: 1, y: 1 },
+        Cell { x: -1, y: 0 },
+        Cell { x: 1, y: 0 },
+    ],
+    // L -> R
+    [
+        Cell { x: 0, y: 0 },
+        Cell { x: -1, y: 0 },
+        Cell { x: -1, y: -2 },
+        Cell { x: -1, y: -1 },
+        Cell { x: 0, y: -2 },
+        Cell { x: 0, y: -1 },
+    ],
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Cell {
     pub x: i16,
     pub y: i16,
@@ -15,25 +59,18 @@ pub struct Block {
     color: Color,
     positions: Vec<Position>,
     cur_pos: usize,
-    pos_rects: Vec<Recti16>,
-    pub rect: Rect,
-}
-
-#[derive(Debug, Clone)]
-struct Recti16 {
-    x: i16,
-    y: i16,
-    width: u16,
-    height: u16,
+    kicks: &'static Kicks,
+    pub corner: Cell,
+    init_corner: Cell,
 }
 
 impl Block {
     pub fn position(&self) -> Position {
         let mut pos = Position::new();
         let cur_pos = &self.positions[self.cur_pos];
-        for i in 0..cur_pos.len() {
-            let x = cur_pos[i].x + self.rect.x as i16;
-            let y = cur_pos[i].y + self.rect.y as i16;
+        for cell in cur_pos {
+            let x = cell.x + self.corner.x;
+            let y = cell.y + self.corner.y;
             pos.push(Cell { x, y })
         }
         pos
@@ -43,116 +80,142 @@ impl Block {
         self.color
     }
 
-    pub fn rotate(&mut self, rect: &Rect, state: &Vec<Vec<Color>>, bg_color: &Color) {
-        // Change to next position
-        self.cur_pos = (self.cur_pos + 1) % self.positions.len();
-        let cur_pos_rect = self.pos_rects[self.cur_pos].clone();
-        let before = self.rect.clone();
-
-        // If the block is in the left corner, move it right enough to rotate
-        while self.rect.x as i16 + cur_pos_rect.x < rect.x as i16 {
-            if !self.move_right(rect, state, bg_color) {
-                self.cur_pos = (self.cur_pos + self.positions.len() - 1) % self.positions.len();
-                return;
-            }
-        }
-        // If the block is in the right cornet, move it left enough to rotate
-        while self.rect.x as i16 + cur_pos_rect.width as i16 + cur_pos_rect.x
-            > (rect.x + rect.width) as i16
-        {
-            if !self.move_left(rect, state, bg_color) {
-                self.cur_pos = (self.cur_pos + self.positions.len() - 1) % self.positions.len();
-                return;
-            }
-        }
-        // If the block is in the bottom, move it up enough to rotate
-        while self.rect.y as i16 + cur_pos_rect.height as i16 + cur_pos_rect.y
-            > (rect.y + rect.height) as i16
-        {
-            if !self.move_up(rect, state, bg_color) {
-                self.cur_pos = (self.cur_pos + self.positions.len() - 1) % self.positions.len();
-                return;
-            }
-        }
+    /// Cells of the current rotation relative to the block's own corner.
+    pub fn shape(&self) -> &Position {
+        &self.positions[self.cur_pos]
+    }
 
-        self.rect.x = (self.rect.x as i16 + cur_pos_rect.x) as u16;
-        self.rect.y = (self.rect.y as i16 + cur_pos_rect.y) as u16;
-        self.rect.width = cur_pos_rect.width;
-        self.rect.height = cur_pos_rect.height;
+    /// Moves the block back to its spawn corner and rotation.
+    pub fn reset(&mut self) {
+        self.cur_pos = 0;
+        self.corner = self.init_corner;
+    }
 
-        if does_intersect(&self.position(), rect, state, bg_color) {
-            self.cur_pos = (self.cur_pos + self.positions.len() - 1) % self.positions.len();
-            self.rect = before;
-        }
+    /// Index of the current rotation state: 0, R, 2, L.
+    pub fn rotation(&self) -> usize {
+        self.cur_pos
     }
 
-    pub fn move_right(&mut self, rect: &Rect, state: &Vec<Vec<Color>>, bg_color: &Color) -> bool {
-        if self.rect.x + self.rect.width + 1 <= rect.width {
-            self.rect.x = self.rect.x + 1;
-            if does_intersect(&self.position(), rect, state, bg_color) {
-                self.rect.x = self.rect.x - 1;
-                return false;
-            }
-            return true;
-        }
-        return false;
+    /// Rotates clockwise, trying the SRS wall kicks in order. Returns the index
+    /// of the kick used, or `None` leaving the block unchanged if none fits.
+    pub fn rotate(&mut self, rect: &Rect, state: &[Vec<Color>], bg_color: &Color) -> Option<usize> {
+        let from = self.cur_pos;
+        let to = (from + 1) % self.positions.len();
+        let kicks = self.kicks[from];
+
+        self.rotate_with_kicks(to, &kicks, rect, state, bg_color)
     }
 
-    pub fn move_left(&mut self, rect: &Rect, state: &Vec<Vec<Color>>, bg_color: &Color) -> bool {
-        if self.rect.x as i16 - 1 >= rect.x as i16 {
-            self.rect.x = self.rect.x - 1;
-            if does_intersect(&self.position(), rect, state, bg_color) {
-                self.rect.x = self.rect.x + 1;
-                return false;
-            }
-            return true;
-        }
-        return false;
+    /// Rotates counter-clockwise. The kicks are the clockwise kicks into the
+    /// current state reversed.
+    pub fn rotate_counter_clockwise(
+        &mut self,
+        rect: &Rect,
+        state: &[Vec<Color>],
+        bg_color: &Color,
+    ) -> Option<usize> {
+        let from = self.cur_pos;
+        let to = (from + self.positions.len() - 1) % self.positions.len();
+        let kicks: Vec<Cell> = self.kicks[to]
+            .iter()
+            .map(|kick| Cell {
+                x: -kick.x,
+                y: -kick.y,
+            })
+            .collect();
+
+        self.rotate_with_kicks(to, &kicks, rect, state, bg_color)
+    }
+
+    /// Turns the block 180 degrees using the SRS+ half turn kicks.
+    pub fn rotate_180(
+        &mut self,
+        rect: &Rect,
+        state: &[Vec<Color>],
+        bg_color: &Color,
+    ) -> Option<usize> {
+        let from = self.cur_pos;
+        let to = (from + 2) % self.positions.len();
+        let kicks = HALF_TURN_KICKS[from];
+
+        self.rotate_with_kicks(to, &kicks, rect, state, bg_color)
     }
 
-    pub fn move_down(&mut self, rect: &Rect, state: &Vec<Vec<Color>>, bg_color: &Color) -> bool {
-        if self.rect.y + self.rect.height + 1 <= rect.height {
-            self.rect.y = self.rect.y + 1;
-            if does_intersect(&self.position(), rect, state, bg_color) {
-                self.rect.y = self.rect.y - 1;
-                return false;
+    fn rotate_with_kicks(
+        &mut self,
+        to: usize,
+        kicks: &[Cell],
+        rect: &Rect,
+        state: &[Vec<Color>],
+        bg_color: &Color,
+    ) -> Option<usize> {
+        let from = self.cur_pos;
+        let before = self.corner;
+
+        self.cur_pos = to;
+        for (index, kick) in kicks.iter().enumerate() {
+            self.corner = Cell {
+                x: before.x + kick.x,
+                y: before.y + kick.y,
+            };
+            if !does_intersect(&self.position(), rect, state, bg_color) {
+                return Some(index);
             }
-            return true;
         }
-        return false;
+
+        self.cur_pos = from;
+        self.corner = before;
+        None
     }
 
-    pub fn move_up(&mut self, rect: &Rect, state: &Vec<Vec<Color>>, bg_color: &Color) -> bool {
-        if self.rect.y as i16 - 1 >= rect.y as i16 {
-            self.rect.y = self.rect.y - 1;
-            if does_intersect(&self.position(), rect, state, bg_color) {
-                self.rect.y = self.rect.y + 1;
-                return false;
-            }
-            return true;
+    pub fn move_right(&mut self, rect: &Rect, state: &[Vec<Color>], bg_color: &Color) -> bool {
+        self.shift(1, 0, rect, state, bg_color)
+    }
+
+    pub fn move_left(&mut self, rect: &Rect, state: &[Vec<Color>], bg_color: &Color) -> bool {
+        self.shift(-1, 0, rect, state, bg_color)
+    }
+
+    pub fn move_down(&mut self, rect: &Rect, state: &[Vec<Color>], bg_color: &Color) -> bool {
+        self.shift(0, 1, rect, state, bg_color)
+    }
+
+    fn shift(
+        &mut self,
+        dx: i16,
+        dy: i16,
+        rect: &Rect,
+        state: &[Vec<Color>],
+        bg_color: &Color,
+    ) -> bool {
+        self.corner.x += dx;
+        self.corner.y += dy;
+        if does_intersect(&self.position(), rect, state, bg_color) {
+            self.corner.x -= dx;
+            self.corner.y -= dy;
+            return false;
         }
-        return false;
+        true
     }
 }
 
+/// Returns true if any cell is outside `rect` or on a cell of `state` that
+/// is not `bg_color`.
 pub fn does_intersect(
-    position: &Position,
+    position: &[Cell],
     rect: &Rect,
-    state: &Vec<Vec<Color>>,
+    state: &[Vec<Color>],
     bg_color: &Color,
 ) -> bool {
     for cell in position {
-        if cell.x >= 0 as i16
-            && cell.x < state.len() as i16
-            && cell.y >= 0 as i16
-            && cell.y < state[cell.x as usize].len() as i16
+        if cell.x < rect.x as i16
+            || cell.x >= (rect.x + rect.width) as i16
+            || cell.y < rect.y as i16
+            || cell.y >= (rect.y + rect.height) as i16
+            || state[cell.x as usize][cell.y as usize] != *bg_color
         {
-            if state[(rect.x + cell.x as u16) as usize][(rect.y + cell.y as u16) as usize]
-                != *bg_color
-            {
-                return true;
-            }
+            return true;
         }
     }
-    return false;
+    false
 }
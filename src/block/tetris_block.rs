@@ -1,24 +1,29 @@
 use crate::block;
 
-use block::{Block, Cell, Position, Recti16};
-use tui::{layout::Rect, style::Color};
+use block::{Block, Cell, Kicks, Position};
+use tui::style::Color;
 
 use lazy_static;
 
+// Rotation states are ordered spawn, clockwise (R), 180 (2), counter-clockwise (L)
+// as in the Super Rotation System. Cells are relative to the block's corner
+// with y growing downwards.
+
 impl Block {
     pub fn new_cyan() -> Block {
         let color = CYAN_COLOR;
         let positions = CYAN_POSITIONS.clone();
         let cur_pos = 0;
-        let pos_rects = CYAN_RECTS.clone();
-        let rect = CYAN_INIT_RECT;
+        let kicks = &CYAN_KICKS;
+        let corner = CYAN_INIT_CORNER;
 
         Block {
             color,
             positions,
             cur_pos,
-            pos_rects,
-            rect,
+            kicks,
+            corner,
+            init_corner: corner,
         }
     }
 
@@ -26,15 +31,16 @@ impl Block {
         let color = BLUE_COLOR;
         let positions = BLUE_POSITIONS.clone();
         let cur_pos = 0;
-        let pos_rects = BOGRP_RECTS.clone();
-        let rect = BOGRP_INIT_RECT;
+        let kicks = &BOGRP_KICKS;
+        let corner = BOGRP_INIT_CORNER;
 
         Block {
             color,
             positions,
             cur_pos,
-            pos_rects,
-            rect,
+            kicks,
+            corner,
+            init_corner: corner,
         }
     }
 
@@ -42,15 +48,16 @@ impl Block {
         let color = ORANGE_COLOR;
         let positions = ORANGE_POSITIONS.clone();
         let cur_pos = 0;
-        let pos_rects = BOGRP_RECTS.clone();
-        let rect = BOGRP_INIT_RECT;
+        let kicks = &BOGRP_KICKS;
+        let corner = BOGRP_INIT_CORNER;
 
         Block {
             color,
             positions,
             cur_pos,
-            pos_rects,
-            rect,
+            kicks,
+            corner,
+            init_corner: corner,
         }
     }
 
@@ -58,15 +65,16 @@ impl Block {
         let color = GREEN_COLOR;
         let positions = GREEN_POSITIONS.clone();
         let cur_pos = 0;
-        let pos_rects = BOGRP_RECTS.clone();
-        let rect = BOGRP_INIT_RECT;
+        let kicks = &BOGRP_KICKS;
+        let corner = BOGRP_INIT_CORNER;
 
         Block {
             color,
             positions,
             cur_pos,
-            pos_rects,
-            rect,
+            kicks,
+            corner,
+            init_corner: corner,
         }
     }
 
@@ -74,15 +82,16 @@ impl Block {
         let color = RED_COLOR;
         let positions = RED_POSITIONS.clone();
         let cur_pos = 0;
-        let pos_rects = BOGRP_RECTS.clone();
-        let rect = BOGRP_INIT_RECT;
+        let kicks = &BOGRP_KICKS;
+        let corner = BOGRP_INIT_CORNER;
 
         Block {
             color,
             positions,
             cur_pos,
-            pos_rects,
-            rect,
+            kicks,
+            corner,
+            init_corner: corner,
         }
     }
 
@@ -90,101 +99,123 @@ impl Block {
         let color = PURPLE_COLOR;
         let positions = PURPLE_POSITIONS.clone();
         let cur_pos = 0;
-        let pos_rects = BOGRP_RECTS.clone();
-        let rect = BOGRP_INIT_RECT;
+        let kicks = &BOGRP_KICKS;
+        let corner = BOGRP_INIT_CORNER;
 
         Block {
             color,
             positions,
             cur_pos,
-            pos_rects,
-            rect,
+            kicks,
+            corner,
+            init_corner: corner,
         }
     }
 
+    /// The purple block is the T block, the only one that can T-spin.
+    pub fn is_purple(&self) -> bool {
+        self.color == PURPLE_COLOR
+    }
+
     pub fn new_yellow() -> Block {
         let color = YELLOW_COLOR;
-        let positions = vec![YELLOW_POS.into()];
+        let positions = vec![YELLOW_POS.into(); 4];
         let cur_pos = 0;
-        let pos_rects = vec![YELLOW_RECT];
-        let rect = YELLOW_INIT_RECT;
+        let kicks = &YELLOW_KICKS;
+        let corner = YELLOW_INIT_CORNER;
 
         Block {
             color,
             positions,
             cur_pos,
-            pos_rects,
-            rect,
+            kicks,
+            corner,
+            init_corner: corner,
         }
     }
 }
 
 // CYAN
-// ----
-const CYAN_POS_HORIZONTAL: [Cell; 4] = [
+// The 4x4 box is shifted one row up so the spawn row is the corner's row.
+// _ _ _ _
+const CYAN_POS_U: [Cell; 4] = [
     Cell { x: 0, y: 0 },
     Cell { x: 1, y: 0 },
     Cell { x: 2, y: 0 },
     Cell { x: 3, y: 0 },
 ];
-// |
-// |
-// |
-// |
-const CYAN_POS_VERTICAL: [Cell; 4] = [
-    Cell { x: 0, y: 0 },
+//     |
+//     |
+//     |
+//     |
+const CYAN_POS_R: [Cell; 4] = [
+    Cell { x: 2, y: -1 },
+    Cell { x: 2, y: 0 },
+    Cell { x: 2, y: 1 },
+    Cell { x: 2, y: 2 },
+];
+//
+// _ _ _ _
+const CYAN_POS_D: [Cell; 4] = [
     Cell { x: 0, y: 1 },
-    Cell { x: 0, y: 2 },
-    Cell { x: 0, y: 3 },
+    Cell { x: 1, y: 1 },
+    Cell { x: 2, y: 1 },
+    Cell { x: 3, y: 1 },
+];
+//   |
+//   |
+//   |
+//   |
+const CYAN_POS_L: [Cell; 4] = [
+    Cell { x: 1, y: -1 },
+    Cell { x: 1, y: 0 },
+    Cell { x: 1, y: 1 },
+    Cell { x: 1, y: 2 },
 ];
 
-const CYAN_RECT_HORIZONTAL_L: Recti16 = Recti16 {
-    x: -1,
-    y: 0,
-    width: 4,
-    height: 1,
-};
-
-const CYAN_RECT_VERTICAL_R: Recti16 = Recti16 {
-    x: 2,
-    y: 0,
-    width: 1,
-    height: 4,
-};
-
-const CYAN_RECT_HORIZONTAL_R: Recti16 = Recti16 {
-    x: -2,
-    y: 0,
-    width: 4,
-    height: 1,
-};
-
-const CYAN_RECT_VERTICAL_L: Recti16 = Recti16 {
-    x: 1,
-    y: 0,
-    width: 1,
-    height: 4,
-};
-
-const CYAN_INIT_RECT: Rect = Rect {
-    x: 3,
-    y: 0,
-    width: 4,
-    height: 1,
-};
+const CYAN_INIT_CORNER: Cell = Cell { x: 3, y: 0 };
+
+const CYAN_KICKS: Kicks = [
+    // 0 -> R
+    [
+        Cell { x: 0, y: 0 },
+        Cell { x: -2, y: 0 },
+        Cell { x: 1, y: 0 },
+        Cell { x: -2, y: 1 },
+        Cell { x: 1, y: -2 },
+    ],
+    // R -> 2
+    [
+        Cell { x: 0, y: 0 },
+        Cell { x: -1, y: 0 },
+        Cell { x: 2, y: 0 },
+        Cell { x: -1, y: -2 },
+        Cell { x: 2, y: 1 },
+    ],
+    // 2 -> L
+    [
+        Cell { x: 0, y: 0 },
+        Cell { x: 2, y: 0 },
+        Cell { x: -1, y: 0 },
+        Cell { x: 2, y: -1 },
+        Cell { x: -1, y: 2 },
+    ],
+    // L -> 0
+    [
+        Cell { x: 0, y: 0 },
+        Cell { x: 1, y: 0 },
+        Cell { x: -2, y: 0 },
+        Cell { x: 1, y: 2 },
+        Cell { x: -2, y: -1 },
+    ],
+];
 
 lazy_static! {
     static ref CYAN_POSITIONS: Vec<Position> = vec![
-        CYAN_POS_HORIZONTAL.into(),
-        CYAN_POS_VERTICAL.into(),
-        CYAN_POS_HORIZONTAL.into(),
-        CYAN_POS_VERTICAL.into()
-    ];
-    static ref CYAN_RECTS: Vec<Recti16> = vec![
-        CYAN_RECT_HORIZONTAL_L,
-        CYAN_RECT_VERTICAL_R,
-        CYAN_RECT_HORIZONTAL_R,
-        CYAN_RECT_VERTICAL_L,
+        CYAN_POS_U.into(),
+        CYAN_POS_R.into(),
+        CYAN_POS_D.into(),
+        CYAN_POS_L.into(),
     ];
 }
 
@@ -199,22 +230,23 @@ const BLUE_POS_U: [Cell; 4] = [
     Cell { x: 1, y: 1 },
     Cell { x: 2, y: 1 },
 ];
-// _ _
-// |
-// |
+//   _ _
+//   |
+//   |
 const BLUE_POS_R: [Cell; 4] = [
-    Cell { x: 0, y: 0 },
-    Cell { x: 0, y: 1 },
-    Cell { x: 0, y: 2 },
     Cell { x: 1, y: 0 },
+    Cell { x: 1, y: 1 },
+    Cell { x: 1, y: 2 },
+    Cell { x: 2, y: 0 },
 ];
+//
 // _ _ _
 //     |
 const BLUE_POS_D: [Cell; 4] = [
-    Cell { x: 0, y: 0 },
-    Cell { x: 1, y: 0 },
-    Cell { x: 2, y: 0 },
+    Cell { x: 0, y: 1 },
+    Cell { x: 1, y: 1 },
     Cell { x: 2, y: 1 },
+    Cell { x: 2, y: 2 },
 ];
 //   |
 //   |
@@ -246,22 +278,23 @@ const ORANGE_POS_U: [Cell; 4] = [
     Cell { x: 2, y: 0 },
     Cell { x: 2, y: 1 },
 ];
-// |
-// |
-// | _
+//   |
+//   |
+//   | _
 const ORANGE_POS_R: [Cell; 4] = [
-    Cell { x: 0, y: 0 },
-    Cell { x: 0, y: 1 },
-    Cell { x: 0, y: 2 },
+    Cell { x: 1, y: 0 },
+    Cell { x: 1, y: 1 },
     Cell { x: 1, y: 2 },
+    Cell { x: 2, y: 2 },
 ];
+//
 // _ _ _
 // |
 const ORANGE_POS_D: [Cell; 4] = [
-    Cell { x: 0, y: 0 },
     Cell { x: 0, y: 1 },
-    Cell { x: 1, y: 0 },
-    Cell { x: 2, y: 0 },
+    Cell { x: 0, y: 2 },
+    Cell { x: 1, y: 1 },
+    Cell { x: 2, y: 1 },
 ];
 // _ _
 //   |
@@ -287,15 +320,32 @@ const ORANGE_COLOR: Color = Color::Rgb(255, 165, 0);
 // GREEN
 //   _ _
 // _ _
-const GREEN_POS_HORIZONTAL: [Cell; 4] = [
+const GREEN_POS_U: [Cell; 4] = [
     Cell { x: 0, y: 1 },
     Cell { x: 1, y: 0 },
     Cell { x: 1, y: 1 },
     Cell { x: 2, y: 0 },
 ];
+//   |_ _
+//      |
+const GREEN_POS_R: [Cell; 4] = [
+    Cell { x: 1, y: 0 },
+    Cell { x: 1, y: 1 },
+    Cell { x: 2, y: 1 },
+    Cell { x: 2, y: 2 },
+];
+//
+//   _ _
+// _ _
+const GREEN_POS_D: [Cell; 4] = [
+    Cell { x: 0, y: 2 },
+    Cell { x: 1, y: 1 },
+    Cell { x: 1, y: 2 },
+    Cell { x: 2, y: 1 },
+];
 // |_ _
 //    |
-const GREEN_POS_VERTICAL: [Cell; 4] = [
+const GREEN_POS_L: [Cell; 4] = [
     Cell { x: 0, y: 0 },
     Cell { x: 0, y: 1 },
     Cell { x: 1, y: 1 },
@@ -304,10 +354,10 @@ const GREEN_POS_VERTICAL: [Cell; 4] = [
 
 lazy_static! {
     static ref GREEN_POSITIONS: Vec<Position> = vec![
-        GREEN_POS_HORIZONTAL.into(),
-        GREEN_POS_VERTICAL.into(),
-        GREEN_POS_HORIZONTAL.into(),
-        GREEN_POS_VERTICAL.into(),
+        GREEN_POS_U.into(),
+        GREEN_POS_R.into(),
+        GREEN_POS_D.into(),
+        GREEN_POS_L.into(),
     ];
 }
 
@@ -316,15 +366,32 @@ const GREEN_COLOR: Color = Color::Green;
 // RED
 // _ _
 //   _ _
-const RED_POS_HORIZONTAL: [Cell; 4] = [
+const RED_POS_U: [Cell; 4] = [
     Cell { x: 0, y: 0 },
     Cell { x: 1, y: 0 },
     Cell { x: 1, y: 1 },
     Cell { x: 2, y: 1 },
 ];
+//   _ _|
+//   |
+const RED_POS_R: [Cell; 4] = [
+    Cell { x: 1, y: 1 },
+    Cell { x: 1, y: 2 },
+    Cell { x: 2, y: 0 },
+    Cell { x: 2, y: 1 },
+];
+//
+// _ _
+//   _ _
+const RED_POS_D: [Cell; 4] = [
+    Cell { x: 0, y: 1 },
+    Cell { x: 1, y: 1 },
+    Cell { x: 1, y: 2 },
+    Cell { x: 2, y: 2 },
+];
 //  _ _|
 //  |
-const RED_POS_VERTICAL: [Cell; 4] = [
+const RED_POS_L: [Cell; 4] = [
     Cell { x: 0, y: 1 },
     Cell { x: 0, y: 2 },
     Cell { x: 1, y: 0 },
@@ -333,10 +400,10 @@ const RED_POS_VERTICAL: [Cell; 4] = [
 
 lazy_static! {
     static ref RED_POSITIONS: Vec<Position> = vec![
-        RED_POS_HORIZONTAL.into(),
-        RED_POS_VERTICAL.into(),
-        RED_POS_HORIZONTAL.into(),
-        RED_POS_VERTICAL.into(),
+        RED_POS_U.into(),
+        RED_POS_R.into(),
+        RED_POS_D.into(),
+        RED_POS_L.into(),
     ];
 }
 
@@ -351,22 +418,23 @@ const PURPLE_POS_U: [Cell; 4] = [
     Cell { x: 1, y: 1 },
     Cell { x: 2, y: 1 },
 ];
-// |
-// | _
-// |
+//   |
+//   | _
+//   |
 const PURPLE_POS_R: [Cell; 4] = [
-    Cell { x: 0, y: 0 },
-    Cell { x: 0, y: 1 },
-    Cell { x: 0, y: 2 },
+    Cell { x: 1, y: 0 },
     Cell { x: 1, y: 1 },
+    Cell { x: 1, y: 2 },
+    Cell { x: 2, y: 1 },
 ];
+//
 // _ _ _
 //   |
 const PURPLE_POS_D: [Cell; 4] = [
-    Cell { x: 0, y: 0 },
-    Cell { x: 1, y: 0 },
+    Cell { x: 0, y: 1 },
     Cell { x: 1, y: 1 },
-    Cell { x: 2, y: 0 },
+    Cell { x: 1, y: 2 },
+    Cell { x: 2, y: 1 },
 ];
 //   |
 // _ |
@@ -389,45 +457,42 @@ lazy_static! {
 const PURPLE_COLOR: Color = Color::Rgb(128, 0, 128);
 
 // BLUE & ORANGE & GREEN & RED & PURPLE
-const BOGRP_RECT_U: Recti16 = Recti16 {
-    x: 0,
-    y: 0,
-    width: 3,
-    height: 2,
-};
-
-const BOGRP_RECT_R: Recti16 = Recti16 {
-    x: 1,
-    y: 0,
-    width: 2,
-    height: 3,
-};
-
-const BOGRP_RECT_D: Recti16 = Recti16 {
-    x: -1,
-    y: 1,
-    width: 3,
-    height: 2,
-};
-
-const BOGRP_RECT_L: Recti16 = Recti16 {
-    x: 0,
-    y: -1,
-    width: 2,
-    height: 3,
-};
-
-const BOGRP_INIT_RECT: Rect = Rect {
-    x: 3,
-    y: 0,
-    width: 3,
-    height: 2,
-};
-
-lazy_static! {
-    static ref BOGRP_RECTS: Vec<Recti16> =
-        vec![BOGRP_RECT_U, BOGRP_RECT_R, BOGRP_RECT_D, BOGRP_RECT_L];
-}
+const BOGRP_INIT_CORNER: Cell = Cell { x: 3, y: 0 };
+
+const BOGRP_KICKS: Kicks = [
+    // 0 -> R
+    [
+        Cell { x: 0, y: 0 },
+        Cell { x: -1, y: 0 },
+        Cell { x: -1, y: -1 },
+        Cell { x: 0, y: 2 },
+        Cell { x: -1, y: 2 },
+    ],
+    // R -> 2
+    [
+        Cell { x: 0, y: 0 },
+        Cell { x: 1, y: 0 },
+        Cell { x: 1, y: 1 },
+        Cell { x: 0, y: -2 },
+        Cell { x: 1, y: -2 },
+    ],
+    // 2 -> L
+    [
+        Cell { x: 0, y: 0 },
+        Cell { x: 1, y: 0 },
+        Cell { x: 1, y: -1 },
+        Cell { x: 0, y: 2 },
+        Cell { x: 1, y: 2 },
+    ],
+    // L -> 0
+    [
+        Cell { x: 0, y: 0 },
+        Cell { x: -1, y: 0 },
+        Cell { x: -1, y: 1 },
+        Cell { x: 0, y: -2 },
+        Cell { x: -1, y: -2 },
+    ],
+];
 
 // YELLOW
 const YELLOW_POS: [Cell; 4] = [
@@ -437,18 +502,9 @@ const YELLOW_POS: [Cell; 4] = [
     Cell { x: 1, y: 1 },
 ];
 
-const YELLOW_RECT: Recti16 = Recti16 {
-    x: 0,
-    y: 0,
-    width: 2,
-    height: 2,
-};
-
-const YELLOW_INIT_RECT: Rect = Rect {
-    x: 4,
-    y: 0,
-    width: 2,
-    height: 2,
-};
+const YELLOW_INIT_CORNER: Cell = Cell { x: 4, y: 0 };
+
+// The yellow block looks the same in every rotation, so it never kicks.
+const YELLOW_KICKS: Kicks = [[Cell { x: 0, y: 0 }; 5]; 4];
 
 const YELLOW_COLOR: Color = Color::Yellow;
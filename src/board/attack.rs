@@ -0,0 +1,143 @@
+use super::spin::Spin;
+
+/// Garbage lines sent for each put down block in versus play.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AttackTable {
+    /// Lines sent for clearing 0 to 4 lines without a spin.
+    pub line_clears: [usize; 5],
+    /// Lines sent for a T-spin mini clearing 0 to 2 lines.
+    pub t_spin_minis: [usize; 3],
+    /// Lines sent for a T-spin clearing 0 to 3 lines.
+    pub t_spins: [usize; 4],
+    /// Extra lines for a clear that gets the back-to-back bonus.
+    pub back_to_back: usize,
+    /// Extra lines by combo count, the last entry is used for longer combos.
+    pub combos: &'static [usize],
+    /// Extra lines for leaving the board empty.
+    pub perfect_clear: usize,
+}
+
+const GUIDELINE_COMBOS: [usize; 11] = [0, 1, 1, 2, 2, 3, 3, 4, 4, 4, 5];
+
+impl Default for AttackTable {
+    fn default() -> AttackTable {
+        AttackTable::guideline()
+    }
+}
+
+impl AttackTable {
+    pub fn guideline() -> AttackTable {
+        AttackTable {
+            line_clears: [0, 0, 1, 2, 4],
+            t_spin_minis: [0, 0, 1],
+            t_spins: [0, 2, 4, 6],
+            back_to_back: 1,
+            combos: &GUIDELINE_COMBOS,
+            perfect_clear: 10,
+        }
+    }
+
+    /// Every cleared line is sent, a full T-spin sends twice as many and a
+    /// T-spin mini just the cleared lines.
+    pub fn classic() -> AttackTable {
+        AttackTable {
+            line_clears: [0, 1, 2, 3, 4],
+            t_spin_minis: [0, 1, 2],
+            t_spins: [0, 2, 4, 6],
+            back_to_back: 0,
+            combos: &[0],
+            perfect_clear: 10,
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<AttackTable> {
+        match name {
+            "guideline" => Some(AttackTable::guideline()),
+            "classic" => Some(AttackTable::classic()),
+            _ => None,
+        }
+    }
+
+    /// Lines sent for a put down block. `combo` is the number of consecutive
+    /// clears minus one, as counted by `Scoring`.
+    pub fn attack(
+        &self,
+        lines: usize,
+        spin: Spin,
+        combo: Option<u32>,
+        back_to_back: bool,
+        perfect_clear: bool,
+    ) -> usize {
+        if lines == 0 {
+            return 0;
+        }
+
+        let mut attack = match spin {
+            Spin::None => self.line_clears[lines.min(4)],
+            Spin::Mini => self.t_spin_minis[lines.min(2)],
+            Spin::Full => self.t_spins[lines.min(3)],
+        };
+        if back_to_back {
+            attack += self.back_to_back;
+        }
+        if let (Some(combo), Some(last)) = (combo, self.combos.last()) {
+            attack += *self.combos.get(combo as usize).unwrap_or(last);
+        }
+        if perfect_clear {
+            attack += self.perfect_clear;
+        }
+        attack
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_clears() {
+        let table = AttackTable::guideline();
+
+        let attacks: Vec<usize> = (0..5)
+            .map(|lines| table.attack(lines, Spin::None, None, false, false))
+            .collect();
+
+        assert_eq!(attacks, vec![0, 0, 1, 2, 4]);
+    }
+
+    #[test]
+    fn test_t_spin_double() {
+        let table = AttackTable::guideline();
+
+        assert_eq!(table.attack(2, Spin::Full, None, false, false), 4);
+    }
+
+    #[test]
+    fn test_back_to_back_and_combo() {
+        let table = AttackTable::guideline();
+
+        assert_eq!(table.attack(4, Spin::None, Some(3), true, false), 4 + 1 + 2);
+    }
+
+    #[test]
+    fn test_long_combo_uses_last_entry() {
+        let table = AttackTable::guideline();
+
+        assert_eq!(table.attack(1, Spin::None, Some(20), false, false), 5);
+    }
+
+    #[test]
+    fn test_no_lines_no_attack() {
+        let table = AttackTable::guideline();
+
+        assert_eq!(table.attack(0, Spin::Full, Some(3), true, false), 0);
+    }
+
+    #[test]
+    fn test_classic() {
+        let table = AttackTable::classic();
+
+        assert_eq!(table.attack(2, Spin::None, Some(5), true, false), 2);
+        assert_eq!(table.attack(4, Spin::None, None, false, true), 14);
+    }
+}
@@ -0,0 +1,30 @@
+/// Why a board's game ended.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GameOverReason {
+    /// A new block spawned overlapping the stack.
+    BlockOut,
+    /// A block was put down entirely above the visible field.
+    LockOut,
+    /// Incoming lines pushed the stack past the top of the board.
+    TopOut,
+    /// The time limit ran out.
+    #[allow(dead_code)]
+    TimeUp,
+    /// The player gave up.
+    Forfeit,
+    /// The board cleared its line goal.
+    GoalReached,
+}
+
+impl GameOverReason {
+    pub fn message(self) -> &'static str {
+        match self {
+            GameOverReason::BlockOut => "Block out",
+            GameOverReason::LockOut => "Lock out",
+            GameOverReason::TopOut => "Top out",
+            GameOverReason::TimeUp => "Time up",
+            GameOverReason::Forfeit => "Forfeit",
+            GameOverReason::GoalReached => "Goal reached",
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use rand::{Rng, RngCore};
+
+/// How the holes of incoming garbage lines are placed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HolePattern {
+    /// Every line of an attack has the hole in the same column.
+    Clean,
+    /// Each line after the first moves the hole with the given percent chance.
+    Messy(u8),
+    /// Every line has the hole in a random column.
+    Cheese,
+}
+
+impl HolePattern {
+    /// Parses "clean", "cheese" or a percent chance like "30".
+    pub fn from_name(name: &str) -> Option<HolePattern> {
+        match name {
+            "clean" => Some(HolePattern::Clean),
+            "cheese" => Some(HolePattern::Cheese),
+            _ => match name.parse() {
+                Ok(percent) if percent <= 100 => Some(HolePattern::Messy(percent)),
+                _ => None,
+            },
+        }
+    }
+
+    /// Hole columns for an attack of `lines` lines on a board `cols` wide.
+    pub fn holes(self, lines: usize, cols: usize, rng: &mut dyn RngCore) -> Vec<usize> {
+        let mut holes: Vec<usize> = Vec::with_capacity(lines);
+        let mut hole = rng.gen_range(0, cols);
+
+        for _ in 0..lines {
+            let should_move = match self {
+                HolePattern::Clean => false,
+                HolePattern::Messy(percent) => rng.gen_range(0, 100) < percent,
+                HolePattern::Cheese => true,
+            };
+            if !holes.is_empty() && should_move {
+                hole = match self {
+                    HolePattern::Cheese => rng.gen_range(0, cols),
+                    // A moved hole never stays in the same column.
+                    _ => (hole + rng.gen_range(1, cols.max(2))) % cols,
+                };
+            }
+            holes.push(hole);
+        }
+        holes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    const COLS: usize = 10;
+
+    #[test]
+    fn test_clean_keeps_hole() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let holes = HolePattern::Clean.holes(8, COLS, &mut rng);
+
+        assert!(holes.iter().all(|hole| *hole == holes[0]));
+    }
+
+    #[test]
+    fn test_always_messy_moves_hole() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let holes = HolePattern::Messy(100).holes(8, COLS, &mut rng);
+
+        assert!(holes.windows(2).all(|pair| pair[0] != pair[1]));
+    }
+
+    #[test]
+    fn test_holes_inside_board() {
+        for pattern in &[
+            HolePattern::Clean,
+            HolePattern::Messy(30),
+            HolePattern::Cheese,
+        ] {
+            let mut rng = StdRng::seed_from_u64(7);
+
+            let holes = pattern.holes(50, COLS, &mut rng);
+
+            assert!(holes.iter().all(|hole| *hole < COLS));
+        }
+    }
+
+    #[test]
+    fn test_same_seed_same_holes() {
+        let first = HolePattern::Cheese.holes(20, COLS, &mut StdRng::seed_from_u64(42));
+        let second = HolePattern::Cheese.holes(20, COLS, &mut StdRng::seed_from_u64(42));
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(HolePattern::from_name("clean"), Some(HolePattern::Clean));
+        assert_eq!(HolePattern::from_name("cheese"), Some(HolePattern::Cheese));
+        assert_eq!(HolePattern::from_name("30"), Some(HolePattern::Messy(30)));
+        assert_eq!(HolePattern::from_name("101"), None);
+    }
+}
@@ -0,0 +1,56 @@
+use std::time::Duration;
+
+/// Gravity is measured in rows per frame at 60 frames per second.
+pub const FRAME: Duration = Duration::from_nanos(1_000_000_000 / 60);
+
+pub const LINES_PER_LEVEL: u32 = 10;
+
+/// Most rows a block can fall in one frame, enough to reach the floor at once.
+pub const MAX_GRAVITY: f64 = 20.0;
+
+// Guideline curve (0.8 - (level - 1) * 0.007) ^ (level - 1) seconds per row,
+// starting at level 1.
+const GRAVITY_TABLE: [f64; 18] = [
+    0.01667, 0.02102, 0.02698, 0.03526, 0.04692, 0.06361, 0.08787, 0.1237, 0.17753, 0.2598,
+    0.38781, 0.59065, 0.91811, 1.45696, 2.36118, 3.9091, 6.61354, 11.43794,
+];
+
+/// Rows per frame the block falls on `level`. Levels past the table are 20G.
+pub fn gravity(level: u32) -> f64 {
+    let index = level.max(1) as usize - 1;
+    if index < GRAVITY_TABLE.len() {
+        GRAVITY_TABLE[index]
+    } else {
+        MAX_GRAVITY
+    }
+}
+
+/// Number of rows gained after `elapsed` time on `level`.
+pub fn rows_for(elapsed: Duration, level: u32) -> f64 {
+    gravity(level) * elapsed.as_secs_f64() / FRAME.as_secs_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_level_one_row_per_second() {
+        let rows = rows_for(Duration::from_secs(1), 1);
+
+        assert!((rows - 1.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_gravity_grows_with_level() {
+        for level in 1..30 {
+            assert!(gravity(level) <= gravity(level + 1));
+        }
+    }
+
+    #[test]
+    fn test_twenty_g() {
+        assert_eq!(gravity(19), MAX_GRAVITY);
+        assert_eq!(gravity(100), MAX_GRAVITY);
+    }
+}
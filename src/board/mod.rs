@@ -1,23 +1,52 @@
+pub mod attack;
+pub mod game_over;
+pub mod garbage;
+pub mod gravity;
+pub mod randomizer;
+pub mod scoring;
+pub mod spin;
 mod tests;
 
-use rand::Rng;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
 use termion::event::Key;
 use tui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     widgets::Widget,
 };
 
-use crate::block::{does_intersect, Block};
-
-pub const ROWS: u16 = 20;
-pub const COLS: u16 = 10;
-const SCORE_FOR_LINE: u32 = COLS as u32 * 3;
+use crate::block::{does_intersect, Block, Position};
+use attack::AttackTable;
+use game_over::GameOverReason;
+use garbage::HolePattern;
+use gravity::LINES_PER_LEVEL;
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+use randomizer::{Randomizer, RandomizerKind, BLOCKS_COUNT};
+use scoring::Scoring;
+
+const DEFAULT_ROWS: u16 = 20;
+const DEFAULT_COLS: u16 = 10;
+/// Narrowest board every block fits into at spawn.
+pub const MIN_COLS: u16 = 4;
+/// Largest board, far past any terminal but small enough that widths in
+/// characters and heights with the hidden rows stay within `u16`.
+pub const MAX_COLS: u16 = 1000;
+pub const MAX_ROWS: u16 = 1000;
+/// Rows above the visible field where blocks spawn and the stack can grow into.
+const DEFAULT_HIDDEN_ROWS: u16 = 20;
+const DEFAULT_PREVIEW_LENGTH: usize = 5;
+const DEFAULT_LOCK_DELAY: Duration = Duration::from_millis(500);
+const DEFAULT_MAX_LOCK_RESETS: u32 = 15;
+const DEFAULT_LINE_CLEAR_DELAY: Duration = Duration::from_millis(300);
+const DEFAULT_ENTRY_DELAY: Duration = Duration::from_millis(100);
+const DEFAULT_SOFT_DROP_FACTOR: f64 = 20.0;
+const CALLOUT_DURATION: Duration = Duration::from_secs(2);
 
 lazy_static! {
-    static ref TETRIS_BLOCKS: [Block; 7] = [
+    static ref TETRIS_BLOCKS: [Block; BLOCKS_COUNT] = [
         Block::new_cyan(),
         Block::new_blue(),
         Block::new_orange(),
@@ -31,7 +60,12 @@ lazy_static! {
         ("right".to_string(), Key::Char('d')),
         ("down".to_string(), Key::Char('s')),
         ("rotate".to_string(), Key::Char('w')),
-        ("put".to_string(), Key::Char('t')),
+        ("hard_drop".to_string(), Key::Char('t')),
+        ("sonic_drop".to_string(), Key::Char('c')),
+        ("hold".to_string(), Key::Char('e')),
+        ("rotate_ccw".to_string(), Key::Char('f')),
+        ("rotate_180".to_string(), Key::Char('g')),
+        ("forfeit".to_string(), Key::Char('x')),
     ]
     .iter()
     .cloned()
@@ -41,36 +75,240 @@ lazy_static! {
         ("right".to_string(), Key::Right),
         ("down".to_string(), Key::Down),
         ("rotate".to_string(), Key::Up),
-        ("put".to_string(), Key::Char('/')),
+        ("hard_drop".to_string(), Key::Char('/')),
+        ("sonic_drop".to_string(), Key::Char('n')),
+        ("hold".to_string(), Key::Char('.')),
+        ("rotate_ccw".to_string(), Key::Char(',')),
+        ("rotate_180".to_string(), Key::Char('m')),
+        ("forfeit".to_string(), Key::Backspace),
     ]
     .iter()
     .cloned()
     .collect();
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Rotation {
+    Clockwise,
+    CounterClockwise,
+    Half,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoardConfig {
+    /// Width of the board in cells.
+    pub cols: u16,
+    /// Height of the visible part of the board in cells.
+    pub rows: u16,
+    /// Number of upcoming blocks kept in the preview queue.
+    pub preview_length: usize,
+    pub randomizer: RandomizerKind,
+    /// Draw the ghost block at the position the current block would drop to.
+    pub show_ghost: bool,
+    /// Time a block may rest on the stack before it is put down.
+    pub lock_delay: Duration,
+    /// How many moves or rotations on the stack restart the lock delay.
+    pub max_lock_resets: u32,
+    /// Time full lines stay on the board before they are removed.
+    pub line_clear_delay: Duration,
+    /// Time between putting a block down, or removing its lines, and the
+    /// next block spawning (ARE).
+    pub entry_delay: Duration,
+    /// How many times faster than gravity the block falls while soft dropping.
+    pub soft_drop_factor: f64,
+    /// Level the game starts on, it rises every `LINES_PER_LEVEL` lines.
+    pub start_level: u32,
+    /// Rows kept above the visible `rows`, they are never drawn.
+    pub hidden_rows: u16,
+    /// Garbage lines sent to the other board.
+    pub attack_table: AttackTable,
+    /// Placement of the holes in received garbage.
+    pub hole_pattern: HolePattern,
+    /// Seed of every random choice, `None` picks a random one. The same seed
+    /// and the same inputs always play the same game, and boards sharing a
+    /// seed get the same blocks.
+    pub seed: Option<u64>,
+    /// The game ends once this many lines are cleared, like in a sprint.
+    pub line_goal: Option<u32>,
+}
+
+impl Default for BoardConfig {
+    fn default() -> BoardConfig {
+        BoardConfig {
+            cols: DEFAULT_COLS,
+            rows: DEFAULT_ROWS,
+            preview_length: DEFAULT_PREVIEW_LENGTH,
+            randomizer: RandomizerKind::SevenBag,
+            show_ghost: true,
+            lock_delay: DEFAULT_LOCK_DELAY,
+            max_lock_resets: DEFAULT_MAX_LOCK_RESETS,
+            line_clear_delay: DEFAULT_LINE_CLEAR_DELAY,
+            entry_delay: DEFAULT_ENTRY_DELAY,
+            soft_drop_factor: DEFAULT_SOFT_DROP_FACTOR,
+            start_level: 1,
+            hidden_rows: DEFAULT_HIDDEN_ROWS,
+            attack_table: AttackTable::default(),
+            hole_pattern: HolePattern::Cheese,
+            seed: None,
+            line_goal: None,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Board {
     keys: HashMap<String, Key>,
     state: Vec<Vec<Color>>,
     rect: Rect,
+    hidden_rows: u16,
     bg_color: Color,
     enemy_lines_color: Color,
     block: Block,
-    has_game_ended: bool,
-    tick_count: u8,
-    score: u32,
+    next_blocks: VecDeque<Block>,
+    randomizer: Box<dyn Randomizer>,
+    seed: u64,
+    // Source of every random choice but the blocks, seeded with `seed`.
+    rng: StdRng,
+    // Blocks are drawn from their own stream, seeded from `rng`, so boards
+    // with the same seed get the same blocks whatever garbage they receive.
+    block_rng: StdRng,
+    held_block: Option<Block>,
+    can_hold: bool,
+    // Direction and kick of the last rotation, `None` if the block moved since.
+    last_kick: Option<(Rotation, usize)>,
+    show_ghost: bool,
+    game_over: Option<GameOverReason>,
+    gravity_progress: f64,
+    soft_drop_factor: f64,
+    // Whether the soft drop key is held.
+    is_soft_dropping: bool,
+    lock_delay: Duration,
+    lock_timer: Duration,
+    max_lock_resets: u32,
+    lock_resets: u32,
+    lowest_row: i16,
+    line_clear_delay: Duration,
+    entry_delay: Duration,
+    // Total wait before the next block spawns, `None` while a block is falling.
+    spawn_delay: Option<Duration>,
+    spawn_timer: Duration,
+    // Full rows shown clearing until the line clear delay is over.
+    clearing_lines: Vec<usize>,
+    // Rotation and hold pressed while waiting, applied at spawn (IRS, IHS).
+    buffered_rotation: Option<Rotation>,
+    buffered_hold: bool,
+    start_level: u32,
+    line_goal: Option<u32>,
+    level: u32,
+    lines: u32,
+    pieces: u32,
+    scoring: Scoring,
+    attack_table: AttackTable,
+    // Attacks waiting to be inserted, oldest first.
+    pending_garbage: VecDeque<usize>,
+    hole_pattern: HolePattern,
+    // Text shown over the board for a while, like "PERFECT CLEAR".
+    callout: Option<String>,
+    callout_timer: Duration,
+    // Time played so far, stops when the game ends.
+    play_time: Duration,
 }
 
 impl Default for Board {
     fn default() -> Board {
-        let keys = DEFAULT_KEYBINDINGS.clone();
+        Board::with_config(DEFAULT_KEYBINDINGS.clone(), BoardConfig::default())
+    }
+}
+
+impl Widget for Board {
+    fn render(mut self, area: Rect, buffer: &mut Buffer) {
+        // Only the rows below the hidden ones are drawn, blocks above the top
+        // edge show just their visible part.
+        for i in 0..self.rect.width * 2 {
+            for j in 0..self.rows() {
+                if i < area.width && j < area.height {
+                    let x = area.x + i;
+                    let y = area.y + j;
+                    let row = (j + self.hidden_rows) as usize;
+                    let style = Style::default().bg(self.state[(i / 2) as usize][row]);
+                    buffer.get_mut(x, y).set_style(style);
+                }
+            }
+        }
+
+        // Clearing lines empty from the middle out during the delay.
+        if !self.clearing_lines.is_empty() {
+            let progress = self.spawn_timer.as_secs_f64() / self.line_clear_delay.as_secs_f64();
+            let cleared_width = (progress * self.rect.width as f64).round() as u16;
+            let first_cleared = (self.rect.width - cleared_width.min(self.rect.width)) / 2;
+            for row in &self.clearing_lines {
+                let j = match (*row as u16).checked_sub(self.hidden_rows) {
+                    Some(j) if j < area.height => j,
+                    _ => continue,
+                };
+                for i in 0..self.rect.width * 2 {
+                    let col = i / 2;
+                    let color = if col >= first_cleared && col < first_cleared + cleared_width {
+                        self.bg_color
+                    } else {
+                        Color::White
+                    };
+                    if i < area.width {
+                        buffer
+                            .get_mut(area.x + i, area.y + j)
+                            .set_style(Style::default().bg(color));
+                    }
+                }
+            }
+        }
+
+        if self.show_ghost && !self.is_spawning() {
+            let style = Style::default().fg(self.block.color()).bg(self.bg_color);
+            for cell in self.ghost_position() {
+                if self.state[cell.x as usize][cell.y as usize] != self.bg_color
+                    || cell.y < self.hidden_rows as i16
+                {
+                    continue;
+                }
+                // Each cell is two characters wide, draw it as an outline.
+                for (i, symbol) in ["[", "]"].iter().enumerate() {
+                    let i = cell.x as u16 * 2 + i as u16;
+                    let j = cell.y as u16 - self.hidden_rows;
+                    if i < area.width && j < area.height {
+                        buffer
+                            .get_mut(area.x + i, area.y + j)
+                            .set_symbol(symbol)
+                            .set_style(style);
+                    }
+                }
+            }
+        }
+
+        if let Some(callout) = &self.callout {
+            let width = (self.rect.width * 2).min(area.width);
+            let x = area.x + width.saturating_sub(callout.len() as u16) / 2;
+            let y = area.y + self.rows() / 2;
+            if y < area.bottom() {
+                let style = Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD);
+                buffer.set_stringn(x, y, callout, width as usize, style);
+            }
+        }
+    }
+}
+
+impl Board {
+    pub fn with_config(key_bindings: HashMap<String, Key>, config: BoardConfig) -> Board {
+        let keys = key_bindings;
         let mut state: Vec<Vec<Color>> = Vec::new();
         let rect = Rect {
             x: 0,
             y: 0,
-            width: COLS,
-            height: ROWS,
+            width: config.cols,
+            height: config.rows + config.hidden_rows,
         };
+        let hidden_rows = config.hidden_rows;
 
         let bg_color = Color::Black;
         let enemy_lines_color = Color::Gray;
@@ -82,95 +320,266 @@ impl Default for Board {
             }
         }
         // Initialize with random block.
-        let mut rng = rand::thread_rng();
-        let block = TETRIS_BLOCKS[(rng.gen::<usize>() % TETRIS_BLOCKS.len()) as usize].clone();
-
-        let has_game_ended = false;
-        let tick_count = 0;
-        let score = 0;
+        let seed = config.seed.unwrap_or_else(rand::random);
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut block_rng = StdRng::seed_from_u64(rng.next_u64());
+        let mut randomizer = config.randomizer.build();
+        let block = next_random_block(randomizer.as_mut(), &mut block_rng);
+        let next_blocks = (0..config.preview_length)
+            .map(|_| next_random_block(randomizer.as_mut(), &mut block_rng))
+            .collect();
+
+        let held_block = None;
+        let can_hold = true;
+        let last_kick = None;
+        let show_ghost = config.show_ghost;
+
+        let game_over = None;
+        let gravity_progress = 0.0;
+        let soft_drop_factor = config.soft_drop_factor;
+        let is_soft_dropping = false;
+        let lock_delay = config.lock_delay;
+        let lock_timer = Duration::default();
+        let max_lock_resets = config.max_lock_resets;
+        let lock_resets = 0;
+        let lowest_row = 0;
+        let line_clear_delay = config.line_clear_delay;
+        let entry_delay = config.entry_delay;
+        let spawn_delay = None;
+        let spawn_timer = Duration::default();
+        let clearing_lines = Vec::new();
+        let buffered_rotation = None;
+        let buffered_hold = false;
+        let start_level = config.start_level;
+        let level = start_level;
+        let line_goal = config.line_goal;
+        let pieces = 0;
+        let lines = 0;
+        let scoring = Scoring::default();
+        let attack_table = config.attack_table;
+        let pending_garbage = VecDeque::new();
+        let hole_pattern = config.hole_pattern;
+        let callout = None;
+        let callout_timer = Duration::default();
+        let play_time = Duration::default();
 
         let mut board = Board {
             keys,
             state,
             rect,
+            hidden_rows,
             bg_color,
             enemy_lines_color,
             block,
-            has_game_ended,
-            tick_count,
-            score,
+            next_blocks,
+            randomizer,
+            seed,
+            rng,
+            block_rng,
+            held_block,
+            can_hold,
+            last_kick,
+            show_ghost,
+            game_over,
+            gravity_progress,
+            soft_drop_factor,
+            is_soft_dropping,
+            lock_delay,
+            lock_timer,
+            max_lock_resets,
+            lock_resets,
+            lowest_row,
+            line_clear_delay,
+            entry_delay,
+            spawn_delay,
+            spawn_timer,
+            clearing_lines,
+            buffered_rotation,
+            buffered_hold,
+            start_level,
+            line_goal,
+            level,
+            lines,
+            pieces,
+            scoring,
+            attack_table,
+            pending_garbage,
+            hole_pattern,
+            callout,
+            callout_timer,
+            play_time,
         };
+        board.place_at_spawn();
+        board.reset_lock();
         board.draw_block();
 
         board
     }
-}
 
-impl Widget for Board {
-    fn render(self, area: Rect, buffer: &mut Buffer) {
-        for i in 0..self.rect.width * 2 {
-            for j in 0..self.rect.height {
-                if i < buffer.area().width && j < buffer.area().height {
-                    let x = area.x + i;
-                    let y = area.y + j;
-                    let style = Style::default().bg(self.state[(i / 2) as usize][j as usize]);
-                    buffer.get_mut(x, y).set_style(style);
-                }
-            }
+    pub fn make_action(&mut self, key: &Key, other_board: Option<&mut Board>) {
+        if self.has_game_ended() {
+            return;
         }
+        if self.is_spawning() {
+            self.buffer_action(key);
+            return;
+        }
+        match key {
+            _ if self.keys.get("left").unwrap() == key => self.move_left(),
+            _ if self.keys.get("right").unwrap() == key => self.move_right(),
+            _ if self.keys.get("down").unwrap() == key => self.soft_drop(),
+            _ if self.keys.get("rotate").unwrap() == key => self.rotate(),
+            _ if self.keys.get("rotate_ccw").unwrap() == key => self.rotate_counter_clockwise(),
+            _ if self.keys.get("rotate_180").unwrap() == key => self.rotate_180(),
+            _ if self.keys.get("hard_drop").unwrap() == key => self.hard_drop(other_board),
+            _ if self.keys.get("sonic_drop").unwrap() == key => self.sonic_drop(),
+            _ if self.keys.get("hold").unwrap() == key => self.hold(),
+            _ if self.keys.get("forfeit").unwrap() == key => self.end_game(GameOverReason::Forfeit),
+            _ => (),
+        };
     }
-}
 
-impl Board {
-    pub fn new_player(key_bindings: HashMap<String, Key>) -> Board {
-        let mut board = Board::default();
-        board.keys = key_bindings;
-        board
-    }
-    pub fn make_action(&mut self, key: &Key, other_board: Option<&mut Board>) {
+    /// Remembers rotations and hold pressed before the next block spawns.
+    fn buffer_action(&mut self, key: &Key) {
         match key {
-            _ if self.keys.get(&"left".to_string()).unwrap() == key => self.move_left(),
-            _ if self.keys.get(&"right".to_string()).unwrap() == key => self.move_right(),
-            _ if self.keys.get(&"down".to_string()).unwrap() == key => {
-                self.move_down();
-                if !self.is_put_down() {
-                    self.score += 1;
-                }
+            _ if self.keys.get("rotate").unwrap() == key => {
+                self.buffered_rotation = Some(Rotation::Clockwise)
             }
-            _ if self.keys.get(&"rotate".to_string()).unwrap() == key => self.rotate(),
-            _ if self.keys.get(&"put".to_string()).unwrap() == key => {
-                self.put_block(other_board);
-                self.score += 5;
+            _ if self.keys.get("rotate_ccw").unwrap() == key => {
+                self.buffered_rotation = Some(Rotation::CounterClockwise)
             }
+            _ if self.keys.get("rotate_180").unwrap() == key => {
+                self.buffered_rotation = Some(Rotation::Half)
+            }
+            _ if self.keys.get("hold").unwrap() == key => self.buffered_hold = true,
+            _ if self.keys.get("forfeit").unwrap() == key => self.end_game(GameOverReason::Forfeit),
             _ => (),
         };
     }
 
     fn move_left(&mut self) {
         self.erase_block();
-        self.block
+        let has_moved = self
+            .block
             .move_left(&self.rect, &self.state, &self.bg_color);
         self.draw_block();
+        if has_moved {
+            self.last_kick = None;
+            self.on_block_moved();
+        }
     }
 
     fn move_right(&mut self) {
         self.erase_block();
-        self.block
+        let has_moved = self
+            .block
             .move_right(&self.rect, &self.state, &self.bg_color);
         self.draw_block();
+        if has_moved {
+            self.last_kick = None;
+            self.on_block_moved();
+        }
     }
 
-    pub fn move_down(&mut self) {
+    pub fn move_down(&mut self) -> bool {
         self.erase_block();
-        self.block
+        let has_moved = self
+            .block
             .move_down(&self.rect, &self.state, &self.bg_color);
         self.draw_block();
+        if has_moved {
+            self.last_kick = None;
+            self.on_block_moved();
+        }
+        has_moved
+    }
+
+    /// Restarts the lock delay after a successful move or rotation. Reaching
+    /// a new lowest row gives back all the resets.
+    fn on_block_moved(&mut self) {
+        if self.block.corner.y > self.lowest_row {
+            self.lowest_row = self.block.corner.y;
+            self.lock_resets = 0;
+            self.lock_timer = Duration::default();
+        } else if self.lock_timer > Duration::default() && self.lock_resets < self.max_lock_resets {
+            self.lock_resets += 1;
+            self.lock_timer = Duration::default();
+        }
+    }
+
+    fn reset_lock(&mut self) {
+        self.last_kick = None;
+        self.lock_timer = Duration::default();
+        self.lock_resets = 0;
+        self.lowest_row = self.block.corner.y;
     }
 
     fn rotate(&mut self) {
         self.erase_block();
-        self.block.rotate(&self.rect, &self.state, &self.bg_color);
+        let kick = self.block.rotate(&self.rect, &self.state, &self.bg_color);
         self.draw_block();
+        self.on_block_rotated(Rotation::Clockwise, kick);
+    }
+
+    fn rotate_counter_clockwise(&mut self) {
+        self.erase_block();
+        let kick = self
+            .block
+            .rotate_counter_clockwise(&self.rect, &self.state, &self.bg_color);
+        self.draw_block();
+        self.on_block_rotated(Rotation::CounterClockwise, kick);
+    }
+
+    fn rotate_180(&mut self) {
+        self.erase_block();
+        let kick = self
+            .block
+            .rotate_180(&self.rect, &self.state, &self.bg_color);
+        self.draw_block();
+        self.on_block_rotated(Rotation::Half, kick);
+    }
+
+    fn on_block_rotated(&mut self, rotation: Rotation, kick: Option<usize>) {
+        if let Some(kick) = kick {
+            self.last_kick = Some((rotation, kick));
+            self.on_block_moved();
+        }
+    }
+
+    fn hold(&mut self) {
+        if !self.can_hold {
+            return;
+        }
+        self.erase_block();
+        self.swap_hold();
+
+        if does_intersect(
+            &self.block.position(),
+            &self.rect,
+            &self.state,
+            &self.bg_color,
+        ) {
+            self.end_game(GameOverReason::BlockOut);
+        }
+        self.draw_block();
+    }
+
+    /// Swaps the block with the held one, or with the next block if nothing
+    /// is held. The block must not be drawn.
+    fn swap_hold(&mut self) {
+        self.block.reset();
+        match self.held_block.take() {
+            Some(held_block) => {
+                self.held_block = Some(std::mem::replace(&mut self.block, held_block));
+            }
+            None => {
+                self.held_block = Some(self.block.clone());
+                self.init_block();
+            }
+        }
+        self.place_at_spawn();
+        self.can_hold = false;
+        self.reset_lock();
     }
 
     fn draw_block(&mut self) {
@@ -187,16 +596,141 @@ impl Board {
         }
     }
 
+    /// Moves the block down a row at once, holding the key keeps it falling
+    /// faster, see `set_soft_drop`.
+    fn soft_drop(&mut self) {
+        if self.move_down() {
+            self.scoring.add_soft_drop(1);
+        }
+    }
+
+    /// Sets whether the soft drop key is held, while it is gravity is
+    /// `soft_drop_factor` times faster and every row scores a soft drop.
+    pub fn set_soft_drop(&mut self, is_soft_dropping: bool) {
+        self.is_soft_dropping = is_soft_dropping;
+    }
+
+    /// Drops the block to the floor without putting it down, scored like a
+    /// soft drop.
+    fn sonic_drop(&mut self) {
+        let mut rows = 0;
+        while self.move_down() {
+            rows += 1;
+        }
+        self.scoring.add_soft_drop(rows);
+    }
+
+    fn hard_drop(&mut self, other_board: Option<&mut Board>) {
+        let mut rows = 0;
+        while self.move_down() {
+            rows += 1;
+        }
+        self.scoring.add_hard_drop(rows);
+        self.put_block(other_board);
+    }
+
     fn put_block(&mut self, other_board: Option<&mut Board>) -> usize {
-        self.erase_block();
-        while self
+        while self.move_down() {}
+        let is_lock_out = self
             .block
-            .move_down(&self.rect, &self.state, &self.bg_color)
-        {}
-        self.draw_block();
-        let num_full_lines = self.remove_full_lines();
-        self.score += SCORE_FOR_LINE * num_full_lines as u32;
+            .position()
+            .iter()
+            .all(|cell| cell.y < self.hidden_rows as i16);
+        let spin = spin::detect(
+            &self.block,
+            self.last_kick,
+            &self.rect,
+            &self.state,
+            &self.bg_color,
+        );
+        if is_lock_out {
+            self.end_game(GameOverReason::LockOut);
+        }
+        let full_lines = self.full_lines();
+        let num_full_lines = full_lines.len();
+        self.scoring.add_lock(num_full_lines, spin, self.level);
+        let is_perfect_clear = num_full_lines > 0 && self.is_empty_without(&full_lines);
+        if is_perfect_clear {
+            self.scoring.add_perfect_clear(num_full_lines, self.level);
+            self.show_callout("PERFECT CLEAR");
+        }
+        self.lines += num_full_lines as u32;
+        self.level = self.start_level.max(self.lines / LINES_PER_LEVEL + 1);
+        self.pieces += 1;
+        if self.line_goal.is_some_and(|goal| self.lines >= goal) {
+            self.end_game(GameOverReason::GoalReached);
+        }
+
+        let mut attack = self.attack_table.attack(
+            num_full_lines,
+            spin,
+            self.scoring.combo(),
+            self.scoring.was_back_to_back(),
+            is_perfect_clear,
+        );
+        if num_full_lines > 0 {
+            attack = self.cancel_garbage(attack);
+        } else {
+            self.insert_garbage();
+        }
+        if let Some(other_board) = other_board {
+            other_board.receive_garbage(attack);
+        };
+
+        let mut spawn_delay = self.entry_delay;
+        if num_full_lines > 0 {
+            spawn_delay += self.line_clear_delay;
+        }
+        self.clearing_lines = full_lines;
+        self.spawn_delay = Some(spawn_delay);
+        self.spawn_timer = Duration::default();
+        self.advance_spawn(Duration::default());
+
+        num_full_lines
+    }
+
+    /// Counts down the line clear and entry delays, removing the cleared
+    /// lines and spawning the next block once they are over.
+    fn advance_spawn(&mut self, elapsed: Duration) {
+        let spawn_delay = match self.spawn_delay {
+            Some(spawn_delay) => spawn_delay,
+            None => return,
+        };
+        self.spawn_timer += elapsed;
+
+        if !self.clearing_lines.is_empty() && self.spawn_timer >= self.line_clear_delay {
+            let lines = std::mem::take(&mut self.clearing_lines);
+            self.remove_lines(&lines);
+        }
+        if self.spawn_timer >= spawn_delay {
+            self.spawn_block();
+        }
+    }
+
+    /// Spawns the next block, holding or rotating it first if that was
+    /// pressed while waiting.
+    fn spawn_block(&mut self) {
+        self.spawn_delay = None;
         self.init_block();
+        self.can_hold = true;
+        self.reset_lock();
+
+        if std::mem::take(&mut self.buffered_hold) {
+            self.swap_hold();
+        }
+        if let Some(rotation) = self.buffered_rotation.take() {
+            match rotation {
+                Rotation::Clockwise => self.block.rotate(&self.rect, &self.state, &self.bg_color),
+                Rotation::CounterClockwise => {
+                    self.block
+                        .rotate_counter_clockwise(&self.rect, &self.state, &self.bg_color)
+                }
+                Rotation::Half => self
+                    .block
+                    .rotate_180(&self.rect, &self.state, &self.bg_color),
+            };
+            self.reset_lock();
+        }
 
         if does_intersect(
             &self.block.position(),
@@ -204,70 +738,137 @@ impl Board {
             &self.state,
             &self.bg_color,
         ) {
-            self.has_game_ended = true;
+            self.end_game(GameOverReason::BlockOut);
         }
         self.draw_block();
+    }
 
-        match other_board {
-            Some(other_board) => {
-                other_board.add_enemy_lines(num_full_lines);
-            }
-            None => (),
-        };
+    /// True while waiting for the next block to spawn.
+    pub fn is_spawning(&self) -> bool {
+        self.spawn_delay.is_some()
+    }
 
-        num_full_lines
+    /// True if no cell of the board is filled outside the given rows.
+    fn is_empty_without(&self, rows: &[usize]) -> bool {
+        self.state.iter().all(|col| {
+            col.iter()
+                .enumerate()
+                .all(|(row, cell)| *cell == self.bg_color || rows.contains(&row))
+        })
     }
 
-    fn init_block(&mut self) {
-        let mut rng = rand::thread_rng();
-        self.block = TETRIS_BLOCKS[(rng.gen::<usize>() % TETRIS_BLOCKS.len()) as usize].clone();
+    fn show_callout(&mut self, text: &str) {
+        self.callout = Some(text.to_string());
+        self.callout_timer = Duration::default();
     }
 
-    fn remove_full_lines(&mut self) -> usize {
-        debug_assert!(self.state.len() >= 1, "State should not be empty");
-        // Holds flags for each line if it full.
-        let mut are_lines_full_flags: Vec<bool> = vec![true; self.state[0].len()];
+    fn init_block(&mut self) {
+        self.next_blocks.push_back(next_random_block(
+            self.randomizer.as_mut(),
+            &mut self.block_rng,
+        ));
+        self.block = self.next_blocks.pop_front().unwrap();
+        self.place_at_spawn();
+    }
 
-        for col in &self.state {
-            for line_index in 0..col.len() {
-                if col[line_index] == self.bg_color {
-                    are_lines_full_flags[line_index] = false;
-                }
-            }
+    /// Moves the block to its spawn position above the visible field with
+    /// its bottom row in the top visible row, so every block shows at once.
+    /// Spawn columns are given for the default width and stay centred on
+    /// other widths.
+    fn place_at_spawn(&mut self) {
+        self.block.reset();
+        self.block.corner.x += (self.rect.width as i16 - DEFAULT_COLS as i16) / 2;
+        if self.hidden_rows > 0 {
+            let bottom = self.block.position().iter().map(|cell| cell.y).max();
+            self.block.corner.y += self.hidden_rows as i16 - bottom.unwrap_or(0);
         }
+    }
 
-        for col in &mut self.state {
-            for line_index in 0..col.len() {
-                if are_lines_full_flags[line_index] {
-                    col.remove(line_index);
-                    col.insert(0, self.bg_color);
-                }
-            }
-        }
+    /// Width of the board in cells.
+    pub fn cols(&self) -> u16 {
+        self.rect.width
+    }
+
+    /// Height of the visible part of the board in cells.
+    pub fn rows(&self) -> u16 {
+        self.rect.height - self.hidden_rows
+    }
+
+    /// Indexes of the rows without an empty cell, from the top.
+    fn full_lines(&self) -> Vec<usize> {
+        debug_assert!(!self.state.is_empty(), "State should not be empty");
+        (0..self.state[0].len())
+            .filter(|row| self.state.iter().all(|col| col[*row] != self.bg_color))
+            .collect()
+    }
 
-        let mut num_full_lines = 0;
-        for line_flag in &are_lines_full_flags {
-            if *line_flag {
-                num_full_lines += 1;
+    /// Removes the given rows, sorted from the top, and moves the rows above
+    /// them down.
+    fn remove_lines(&mut self, rows: &[usize]) {
+        for col in &mut self.state {
+            for row in rows {
+                col.remove(*row);
+                col.insert(0, self.bg_color);
             }
         }
-        num_full_lines
     }
 
     pub fn has_game_ended(&self) -> bool {
-        self.has_game_ended
+        self.game_over.is_some()
     }
 
-    pub fn tick_count(&mut self, other_board: Option<&mut Board>) {
-        if self.is_put_down() {
-            self.tick_count += 1;
-        } else {
-            self.tick_count = 0;
+    pub fn game_over_reason(&self) -> Option<GameOverReason> {
+        self.game_over
+    }
+
+    /// Ends the game unless it has already ended for another reason.
+    pub fn end_game(&mut self, reason: GameOverReason) {
+        if self.game_over.is_none() {
+            self.game_over = Some(reason);
+        }
+    }
+
+    /// Advances gravity and the lock delay, or the wait for the next block,
+    /// by `elapsed`.
+    pub fn tick(&mut self, elapsed: Duration, other_board: Option<&mut Board>) {
+        if self.has_game_ended() {
+            return;
+        }
+        self.play_time += elapsed;
+
+        if self.callout.is_some() {
+            self.callout_timer += elapsed;
+            if self.callout_timer >= CALLOUT_DURATION {
+                self.callout = None;
+            }
+        }
+
+        if self.is_spawning() {
+            self.advance_spawn(elapsed);
+            return;
         }
 
-        if self.tick_count == 3 {
-            self.put_block(other_board);
-            self.tick_count = 0;
+        let mut rows = gravity::rows_for(elapsed, self.level);
+        if self.is_soft_dropping {
+            rows *= self.soft_drop_factor;
+        }
+        self.gravity_progress += rows;
+        while self.gravity_progress >= 1.0 {
+            self.gravity_progress -= 1.0;
+            if !self.move_down() {
+                self.gravity_progress = 0.0;
+            } else if self.is_soft_dropping {
+                self.scoring.add_soft_drop(1);
+            }
+        }
+
+        if self.is_put_down() {
+            self.lock_timer += elapsed;
+            if self.lock_timer >= self.lock_delay {
+                self.put_block(other_board);
+            }
+        } else {
+            self.lock_timer = Duration::default();
         }
     }
 
@@ -279,35 +880,126 @@ impl Board {
         ans
     }
 
+    /// Position the current block would land on if put down now.
+    pub fn ghost_position(&mut self) -> Position {
+        let mut ghost = self.block.clone();
+        self.erase_block();
+        while ghost.move_down(&self.rect, &self.state, &self.bg_color) {}
+        self.draw_block();
+        ghost.position()
+    }
+
+    pub fn level(&self) -> u32 {
+        self.level
+    }
+
+    pub fn lines(&self) -> u32 {
+        self.lines
+    }
+
+    /// Lines that end the game once cleared, if any.
+    pub fn line_goal(&self) -> Option<u32> {
+        self.line_goal
+    }
+
+    /// Blocks put down so far.
+    pub fn pieces(&self) -> u32 {
+        self.pieces
+    }
+
     pub fn score(&self) -> u32 {
-        self.score
+        self.scoring.score()
+    }
+
+    pub fn play_time(&self) -> Duration {
+        self.play_time
+    }
+
+    pub fn next_blocks(&self) -> Vec<Block> {
+        self.next_blocks.iter().cloned().collect()
+    }
+
+    pub fn held_block(&self) -> Option<Block> {
+        self.held_block.clone()
+    }
+
+    pub fn can_hold(&self) -> bool {
+        self.can_hold
+    }
+
+    /// Seed the game was started with.
+    pub fn seed(&self) -> u64 {
+        self.seed
     }
 
     pub fn keys(&self) -> HashMap<String, Key> {
         self.keys.clone()
     }
 
-    fn add_enemy_line(&mut self) {
-        self.erase_block();
+    /// Total lines waiting to be inserted.
+    pub fn pending_garbage(&self) -> usize {
+        self.pending_garbage.iter().sum()
+    }
 
-        let mut rng = rand::thread_rng();
-        let rand_col_index = rng.gen::<usize>() % self.state.len();
+    /// Queues an attack, it is inserted when this board next puts down a
+    /// block without clearing lines.
+    fn receive_garbage(&mut self, lines: usize) {
+        if lines > 0 {
+            self.pending_garbage.push_back(lines);
+        }
+    }
+
+    /// Cancels pending garbage with an attack, oldest first, and returns the
+    /// part of the attack left to send.
+    fn cancel_garbage(&mut self, mut attack: usize) -> usize {
+        while let Some(lines) = self.pending_garbage.front_mut() {
+            if attack == 0 {
+                break;
+            }
+            let cancelled = attack.min(*lines);
+            *lines -= cancelled;
+            attack -= cancelled;
+            if *lines == 0 {
+                self.pending_garbage.pop_front();
+            }
+        }
+        attack
+    }
+
+    /// Inserts all pending garbage under the stack, including the block that
+    /// was just put down.
+    fn insert_garbage(&mut self) {
+        while let Some(lines) = self.pending_garbage.pop_front() {
+            self.add_enemy_lines(lines);
+        }
+    }
+
+    fn add_enemy_line(&mut self, hole: usize) {
+        if self.state.iter().any(|col| col[0] != self.bg_color) {
+            self.end_game(GameOverReason::TopOut);
+        }
 
         for col_index in 0..self.state.len() {
             let col = &mut self.state[col_index];
             col.remove(0);
-            if col_index == rand_col_index {
+            if col_index == hole {
                 col.push(self.bg_color)
             } else {
                 col.push(self.enemy_lines_color);
             }
         }
-        self.draw_block();
     }
 
     fn add_enemy_lines(&mut self, num_lines: usize) {
-        for _ in 0..num_lines {
-            self.add_enemy_line()
+        let holes = self
+            .hole_pattern
+            .holes(num_lines, self.state.len(), &mut self.rng);
+        for hole in holes {
+            self.add_enemy_line(hole)
         }
     }
 }
+
+fn next_random_block(randomizer: &mut dyn Randomizer, rng: &mut dyn RngCore) -> Block {
+    TETRIS_BLOCKS[randomizer.next(rng)].clone()
+}
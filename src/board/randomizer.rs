@@ -0,0 +1,218 @@
+use rand::seq::SliceRandom;
+use rand::{Rng, RngCore};
+use std::collections::VecDeque;
+use std::fmt::Debug;
+
+/// Number of different tetris blocks the randomizers choose from.
+pub const BLOCKS_COUNT: usize = 7;
+
+// Indexes of the blocks in `TETRIS_BLOCKS` that make a bad first piece.
+const GREEN_INDEX: usize = 3;
+const RED_INDEX: usize = 4;
+const YELLOW_INDEX: usize = 6;
+
+const HISTORY_ROLLS: usize = 4;
+
+/// Chooses which block comes next. Returns indexes in `0..BLOCKS_COUNT`.
+pub trait Randomizer: Debug {
+    fn next(&mut self, rng: &mut dyn RngCore) -> usize;
+
+    fn clone_box(&self) -> Box<dyn Randomizer>;
+}
+
+impl Clone for Box<dyn Randomizer> {
+    fn clone(&self) -> Box<dyn Randomizer> {
+        self.clone_box()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RandomizerKind {
+    PureRandom,
+    SevenBag,
+    FourteenBag,
+    History,
+}
+
+impl RandomizerKind {
+    pub fn from_name(name: &str) -> Option<RandomizerKind> {
+        match name {
+            "random" => Some(RandomizerKind::PureRandom),
+            "7bag" => Some(RandomizerKind::SevenBag),
+            "14bag" => Some(RandomizerKind::FourteenBag),
+            "history" => Some(RandomizerKind::History),
+            _ => None,
+        }
+    }
+
+    pub fn build(self) -> Box<dyn Randomizer> {
+        match self {
+            RandomizerKind::PureRandom => Box::new(PureRandom),
+            RandomizerKind::SevenBag => Box::new(Bag::new(1)),
+            RandomizerKind::FourteenBag => Box::new(Bag::new(2)),
+            RandomizerKind::History => Box::new(History::new(HISTORY_ROLLS)),
+        }
+    }
+}
+
+/// Every block has the same chance every time.
+#[derive(Debug, Clone)]
+pub struct PureRandom;
+
+impl Randomizer for PureRandom {
+    fn next(&mut self, rng: &mut dyn RngCore) -> usize {
+        rng.gen::<usize>() % BLOCKS_COUNT
+    }
+
+    fn clone_box(&self) -> Box<dyn Randomizer> {
+        Box::new(self.clone())
+    }
+}
+
+/// Deals `copies` of every block in a shuffled order before refilling.
+#[derive(Debug, Clone)]
+pub struct Bag {
+    copies: usize,
+    bag: Vec<usize>,
+}
+
+impl Bag {
+    pub fn new(copies: usize) -> Bag {
+        Bag {
+            copies,
+            bag: Vec::new(),
+        }
+    }
+}
+
+impl Randomizer for Bag {
+    fn next(&mut self, rng: &mut dyn RngCore) -> usize {
+        if self.bag.is_empty() {
+            for _ in 0..self.copies {
+                self.bag.extend(0..BLOCKS_COUNT);
+            }
+            self.bag.shuffle(rng);
+        }
+        self.bag.pop().unwrap()
+    }
+
+    fn clone_box(&self) -> Box<dyn Randomizer> {
+        Box::new(self.clone())
+    }
+}
+
+/// TGM style randomizer: draws up to `rolls` times in all while the block is
+/// one of the last four dealt, and never starts with green, red or yellow.
+#[derive(Debug, Clone)]
+pub struct History {
+    rolls: usize,
+    history: VecDeque<usize>,
+    is_first: bool,
+}
+
+impl History {
+    pub fn new(rolls: usize) -> History {
+        History {
+            rolls,
+            history: vec![RED_INDEX; 4].into_iter().collect(),
+            is_first: true,
+        }
+    }
+}
+
+impl Randomizer for History {
+    fn next(&mut self, rng: &mut dyn RngCore) -> usize {
+        let mut index = rng.gen::<usize>() % BLOCKS_COUNT;
+        if self.is_first {
+            while [GREEN_INDEX, RED_INDEX, YELLOW_INDEX].contains(&index) {
+                index = rng.gen::<usize>() % BLOCKS_COUNT;
+            }
+            self.is_first = false;
+        } else {
+            for _ in 1..self.rolls {
+                if !self.history.contains(&index) {
+                    break;
+                }
+                index = rng.gen::<usize>() % BLOCKS_COUNT;
+            }
+        }
+
+        self.history.pop_front();
+        self.history.push_back(index);
+        index
+    }
+
+    fn clone_box(&self) -> Box<dyn Randomizer> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn deal(randomizer: &mut dyn Randomizer, count: usize) -> Vec<usize> {
+        deal_seeded(randomizer, count, 1)
+    }
+
+    fn deal_seeded(randomizer: &mut dyn Randomizer, count: usize, seed: u64) -> Vec<usize> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..count).map(|_| randomizer.next(&mut rng)).collect()
+    }
+
+    fn count_of(blocks: &[usize], index: usize) -> usize {
+        blocks.iter().filter(|block| **block == index).count()
+    }
+
+    #[test]
+    fn test_pure_random_in_range() {
+        let blocks = deal(&mut PureRandom, 100);
+
+        assert!(blocks.iter().all(|block| *block < BLOCKS_COUNT));
+    }
+
+    #[test]
+    fn test_seven_bag() {
+        let blocks = deal(RandomizerKind::SevenBag.build().as_mut(), 70);
+
+        for bag in blocks.chunks(BLOCKS_COUNT) {
+            for index in 0..BLOCKS_COUNT {
+                assert_eq!(count_of(bag, index), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fourteen_bag() {
+        let blocks = deal(RandomizerKind::FourteenBag.build().as_mut(), 70);
+
+        for bag in blocks.chunks(BLOCKS_COUNT * 2) {
+            for index in 0..BLOCKS_COUNT {
+                assert_eq!(count_of(bag, index), 2);
+            }
+        }
+    }
+
+    #[test]
+    fn test_history_first_block() {
+        for seed in 0..100 {
+            let first = deal_seeded(RandomizerKind::History.build().as_mut(), 1, seed)[0];
+
+            assert_ne!(first, GREEN_INDEX);
+            assert_ne!(first, RED_INDEX);
+            assert_ne!(first, YELLOW_INDEX);
+        }
+    }
+
+    #[test]
+    fn test_history_rerolls() {
+        // With this many rolls a block from the history is practically never dealt.
+        let blocks = deal(&mut History::new(100), 1000);
+
+        for window in blocks.windows(2) {
+            assert_ne!(window[0], window[1]);
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use super::spin::Spin;
+
+/// Points for clearing 0 to 4 lines at once, multiplied by the level.
+const LINE_CLEAR_SCORES: [u32; 5] = [0, 100, 300, 500, 800];
+const T_SPIN_MINI_SCORES: [u32; 3] = [100, 200, 400];
+const T_SPIN_SCORES: [u32; 4] = [400, 800, 1200, 1600];
+/// Bonus for clearing 1 to 4 lines that leave the board empty.
+const PERFECT_CLEAR_SCORES: [u32; 5] = [0, 800, 1200, 1800, 2000];
+const BACK_TO_BACK_TETRIS_PERFECT_CLEAR_SCORE: u32 = 3200;
+const COMBO_SCORE: u32 = 50;
+const SOFT_DROP_SCORE: u32 = 1;
+const HARD_DROP_SCORE: u32 = 2;
+
+/// Guideline scoring: line clears scaled by level, combos for consecutive
+/// clears and a back-to-back bonus for consecutive difficult clears.
+#[derive(Debug, Clone, Default)]
+pub struct Scoring {
+    score: u32,
+    // Number of consecutive clears minus one, `None` after a lock without a clear.
+    combo: Option<u32>,
+    back_to_back: bool,
+    // Whether the last clear got the back-to-back bonus.
+    was_back_to_back: bool,
+}
+
+impl Scoring {
+    /// Scores a put down block that cleared `lines` lines and returns the points.
+    pub fn add_lock(&mut self, lines: usize, spin: Spin, level: u32) -> u32 {
+        let mut points = match spin {
+            Spin::None => LINE_CLEAR_SCORES[lines.min(4)],
+            Spin::Mini => T_SPIN_MINI_SCORES[lines.min(2)],
+            Spin::Full => T_SPIN_SCORES[lines.min(3)],
+        } * level;
+
+        if lines == 0 {
+            self.combo = None;
+            self.score += points;
+            return points;
+        }
+
+        let is_difficult = lines >= 4 || spin != Spin::None;
+        self.was_back_to_back = is_difficult && self.back_to_back;
+        if self.was_back_to_back {
+            points = points * 3 / 2;
+        }
+        self.back_to_back = is_difficult;
+
+        let combo = self.combo.map_or(0, |combo| combo + 1);
+        points += COMBO_SCORE * combo * level;
+        self.combo = Some(combo);
+
+        self.score += points;
+        points
+    }
+
+    /// Adds the perfect clear bonus on top of the points from `add_lock`.
+    pub fn add_perfect_clear(&mut self, lines: usize, level: u32) -> u32 {
+        let points = if lines >= 4 && self.was_back_to_back {
+            BACK_TO_BACK_TETRIS_PERFECT_CLEAR_SCORE
+        } else {
+            PERFECT_CLEAR_SCORES[lines.min(4)]
+        } * level;
+
+        self.score += points;
+        points
+    }
+
+    pub fn add_soft_drop(&mut self, rows: u32) {
+        self.score += SOFT_DROP_SCORE * rows;
+    }
+
+    pub fn add_hard_drop(&mut self, rows: u32) {
+        self.score += HARD_DROP_SCORE * rows;
+    }
+
+    pub fn score(&self) -> u32 {
+        self.score
+    }
+
+    /// Number of consecutive clears minus one, `None` after a lock without a clear.
+    pub fn combo(&self) -> Option<u32> {
+        self.combo
+    }
+
+    /// Whether the last clear got the back-to-back bonus.
+    pub fn was_back_to_back(&self) -> bool {
+        self.was_back_to_back
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_clears_scale_with_level() {
+        let mut scoring = Scoring::default();
+
+        assert_eq!(scoring.add_lock(1, Spin::None, 1), 100);
+        scoring.add_lock(0, Spin::None, 1);
+        assert_eq!(scoring.add_lock(2, Spin::None, 2), 600);
+        scoring.add_lock(0, Spin::None, 1);
+        assert_eq!(scoring.add_lock(3, Spin::None, 3), 1500);
+        scoring.add_lock(0, Spin::None, 1);
+        assert_eq!(scoring.add_lock(4, Spin::None, 4), 3200);
+    }
+
+    #[test]
+    fn test_combo() {
+        let mut scoring = Scoring::default();
+
+        assert_eq!(scoring.add_lock(1, Spin::None, 1), 100);
+        assert_eq!(scoring.add_lock(1, Spin::None, 1), 150);
+        assert_eq!(scoring.add_lock(1, Spin::None, 1), 200);
+
+        scoring.add_lock(0, Spin::None, 1);
+        assert_eq!(scoring.add_lock(1, Spin::None, 1), 100);
+    }
+
+    #[test]
+    fn test_back_to_back() {
+        let mut scoring = Scoring::default();
+
+        assert_eq!(scoring.add_lock(4, Spin::None, 1), 800);
+        scoring.add_lock(0, Spin::None, 1);
+        assert_eq!(scoring.add_lock(4, Spin::None, 1), 1200);
+    }
+
+    #[test]
+    fn test_easy_clear_breaks_back_to_back() {
+        let mut scoring = Scoring::default();
+
+        scoring.add_lock(4, Spin::None, 1);
+        scoring.add_lock(0, Spin::None, 1);
+        scoring.add_lock(1, Spin::None, 1);
+        scoring.add_lock(0, Spin::None, 1);
+
+        assert_eq!(scoring.add_lock(4, Spin::None, 1), 800);
+    }
+
+    #[test]
+    fn test_t_spins() {
+        let mut scoring = Scoring::default();
+
+        assert_eq!(scoring.add_lock(0, Spin::Mini, 1), 100);
+        assert_eq!(scoring.add_lock(0, Spin::Full, 1), 400);
+        assert_eq!(scoring.add_lock(1, Spin::Mini, 1), 200);
+        scoring.add_lock(1, Spin::None, 1);
+        scoring.add_lock(0, Spin::None, 1);
+        assert_eq!(scoring.add_lock(2, Spin::Full, 2), 2400);
+    }
+
+    #[test]
+    fn test_t_spin_back_to_back() {
+        let mut scoring = Scoring::default();
+
+        scoring.add_lock(4, Spin::None, 1);
+        scoring.add_lock(0, Spin::Full, 1);
+        scoring.add_lock(0, Spin::None, 1);
+
+        assert_eq!(scoring.add_lock(2, Spin::Full, 1), 1800);
+    }
+
+    #[test]
+    fn test_perfect_clear() {
+        let mut scoring = Scoring::default();
+
+        scoring.add_lock(2, Spin::None, 1);
+
+        assert_eq!(scoring.add_perfect_clear(2, 1), 1200);
+    }
+
+    #[test]
+    fn test_back_to_back_tetris_perfect_clear() {
+        let mut scoring = Scoring::default();
+
+        scoring.add_lock(4, Spin::None, 1);
+        scoring.add_lock(0, Spin::None, 1);
+        scoring.add_lock(4, Spin::None, 2);
+
+        assert_eq!(scoring.add_perfect_clear(4, 2), 6400);
+    }
+
+    #[test]
+    fn test_drops() {
+        let mut scoring = Scoring::default();
+
+        scoring.add_soft_drop(3);
+        scoring.add_hard_drop(10);
+
+        assert_eq!(scoring.score(), 23);
+    }
+}
@@ -0,0 +1,71 @@
+use tui::{layout::Rect, style::Color};
+
+use super::Rotation;
+use crate::block::{does_intersect, Block, Cell};
+
+// Kick index of the last SRS kick, a T-spin with it always counts as full.
+// 180 degree kicks come from another table where it is a plain shift.
+const LAST_KICK: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Spin {
+    None,
+    Mini,
+    Full,
+}
+
+/// Corners of the purple block's 3x3 box, clockwise from the top left.
+const CORNERS: [Cell; 4] = [
+    Cell { x: 0, y: 0 },
+    Cell { x: 2, y: 0 },
+    Cell { x: 2, y: 2 },
+    Cell { x: 0, y: 2 },
+];
+
+/// Classifies a put down block with the 3-corner rule. `last_kick` is the
+/// direction and kick of the last rotation, or `None` if the block moved after
+/// rotating.
+/// Walls and the floor count as occupied corners.
+pub fn detect(
+    block: &Block,
+    last_kick: Option<(Rotation, usize)>,
+    rect: &Rect,
+    state: &[Vec<Color>],
+    bg_color: &Color,
+) -> Spin {
+    let (direction, kick) = match last_kick {
+        Some(last_kick) if block.is_purple() => last_kick,
+        _ => return Spin::None,
+    };
+
+    let is_occupied: Vec<bool> = CORNERS
+        .iter()
+        .map(|corner| {
+            let cell = Cell {
+                x: block.corner.x + corner.x,
+                y: block.corner.y + corner.y,
+            };
+            does_intersect(&[cell], rect, state, bg_color)
+        })
+        .collect();
+
+    if is_occupied
+        .iter()
+        .filter(|is_occupied| **is_occupied)
+        .count()
+        < 3
+    {
+        return Spin::None;
+    }
+
+    // The two corners beside the block's point: rotation 0 points up between
+    // corners 0 and 1, each clockwise rotation moves them on by one.
+    let rotation = block.rotation();
+    let front = [rotation % 4, (rotation + 1) % 4];
+    let is_last_kick = kick == LAST_KICK && direction != Rotation::Half;
+    if front.iter().all(|index| is_occupied[*index]) || is_last_kick {
+        Spin::Full
+    } else {
+        Spin::Mini
+    }
+}
@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod dimensions {
+    use crate::board::tests::*;
+    use tui::buffer::Buffer;
+    use tui::layout::Rect;
+    use tui::widgets::Widget;
+
+    fn sized_board(cols: u16, rows: u16) -> Board {
+        let config = BoardConfig {
+            cols,
+            rows,
+            hidden_rows: 0,
+            ..BoardConfig::default()
+        };
+        Board::with_config(DEFAULT_KEYBINDINGS.clone(), config)
+    }
+
+    #[test]
+    fn test_state_size() {
+        let board = sized_board(20, 40);
+
+        assert_eq!(board.state.len(), 20);
+        assert_eq!(board.state[0].len(), 40);
+        assert_eq!((board.cols(), board.rows()), (20, 40));
+    }
+
+    #[test]
+    fn test_spawns_inside_narrow_board() {
+        let mut board = sized_board(4, 20);
+
+        for name in &["cyan", "blue", "orange", "green", "red", "purple", "yellow"] {
+            board.erase_block();
+            board.block = get_block(name);
+            board.place_at_spawn();
+
+            assert!(board.block.position().iter().all(|cell| cell.x < 4));
+            assert!(board.block.position().iter().all(|cell| cell.x >= 0));
+        }
+    }
+
+    #[test]
+    fn test_spawns_centred_on_wide_board() {
+        let mut board = sized_board(20, 20);
+        board.erase_block();
+        board.block = get_block("cyan");
+
+        board.place_at_spawn();
+
+        assert_eq!(board.block.corner.x, 8);
+    }
+
+    #[test]
+    fn test_clears_full_line_on_narrow_board() {
+        let mut board = sized_board(4, 20);
+        board.erase_block();
+        board.block = get_block("cyan");
+        board.place_at_spawn();
+
+        assert_eq!(board.put_block(None), 1);
+    }
+
+    #[test]
+    fn test_render_clips_to_area() {
+        let mut board = sized_board(20, 20);
+        board.show_callout("PERFECT CLEAR");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 100, 30));
+        let outside = buffer.get(0, 1).clone();
+
+        // The board is 40 characters wide but only gets the last 31 columns.
+        board.render(Rect::new(69, 0, 31, 20), &mut buffer);
+
+        assert_ne!(buffer.get(99, 0), &outside);
+        assert_eq!(buffer.get(0, 1), &outside);
+        assert_eq!(buffer.get(68, 0), &outside);
+    }
+}
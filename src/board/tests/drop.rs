@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod drop {
+    use crate::board::tests::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_hard_drop_scores_rows() {
+        let color_state = from_char_to_color(&EMPTY_BOARD.clone());
+        let mut board = Board::from_data(&color_state, &get_block("cyan"), None);
+        board.draw_block();
+
+        board.hard_drop(None);
+
+        assert_eq!(board.score(), 2 * 19);
+    }
+
+    #[test]
+    fn test_soft_drop_scores_row() {
+        let color_state = from_char_to_color(&EMPTY_BOARD.clone());
+        let mut board = Board::from_data(&color_state, &get_block("cyan"), None);
+        board.draw_block();
+
+        board.soft_drop();
+
+        assert_eq!(board.score(), 1);
+    }
+
+    #[test]
+    fn test_soft_drop_on_floor_scores_nothing() {
+        let color_state = from_char_to_color(&EMPTY_BOARD.clone());
+        let mut board =
+            Board::from_data(&color_state, &get_block("cyan"), Some(Cell { x: 3, y: 19 }));
+        board.draw_block();
+
+        board.soft_drop();
+
+        assert_eq!(board.score(), 0);
+    }
+
+    #[test]
+    fn test_held_soft_drop_falls_faster() {
+        let color_state = from_char_to_color(&EMPTY_BOARD.clone());
+        let mut board = Board::from_data(&color_state, &get_block("cyan"), None);
+        board.draw_block();
+        board.soft_drop_factor = 20.0;
+        board.set_soft_drop(true);
+
+        // One row per second on level 1, twenty while soft dropping.
+        board.tick(Duration::from_millis(500), None);
+
+        assert_eq!(board.block.corner.y, 10);
+        assert_eq!(board.score(), 10);
+    }
+
+    #[test]
+    fn test_released_soft_drop_scores_nothing() {
+        let color_state = from_char_to_color(&EMPTY_BOARD.clone());
+        let mut board = Board::from_data(&color_state, &get_block("cyan"), None);
+        board.draw_block();
+        board.set_soft_drop(false);
+
+        board.tick(Duration::from_secs(2), None);
+
+        assert_eq!(board.block.corner.y, 2);
+        assert_eq!(board.score(), 0);
+    }
+
+    #[test]
+    fn test_sonic_drop_does_not_lock() {
+        let color_state = from_char_to_color(&EMPTY_BOARD.clone());
+        let mut board = Board::from_data(&color_state, &get_block("cyan"), None);
+        board.draw_block();
+        let next_color = board.next_blocks[0].color();
+
+        board.sonic_drop();
+
+        assert_eq!(board.block.corner.y, 19);
+        assert_eq!(board.next_blocks[0].color(), next_color);
+        assert_eq!(board.score(), 19);
+    }
+}
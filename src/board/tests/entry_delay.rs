@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod entry_delay {
+    use crate::board::tests::*;
+    use std::time::Duration;
+
+    const LINE_CLEAR_DELAY: Duration = Duration::from_millis(300);
+    const ENTRY_DELAY: Duration = Duration::from_millis(100);
+
+    fn delayed(mut board: Board) -> Board {
+        board.line_clear_delay = LINE_CLEAR_DELAY;
+        board.entry_delay = ENTRY_DELAY;
+        board
+    }
+
+    fn delayed_empty_board() -> Board {
+        let color_state = from_bottom_rows(&[]);
+        let mut board = Board::from_data(&color_state, &get_block("cyan"), None);
+        board.draw_block();
+        delayed(board)
+    }
+
+    fn is_bottom_full(board: &Board) -> bool {
+        board.state.iter().all(|col| col[19] != *EMPTY_COLOR)
+    }
+
+    #[test]
+    fn test_lines_stay_during_line_clear_delay() {
+        let mut board = delayed(one_line_board());
+
+        assert_eq!(board.put_block(None), 1);
+
+        assert!(board.is_spawning());
+        assert!(is_bottom_full(&board));
+    }
+
+    #[test]
+    fn test_lines_removed_after_line_clear_delay() {
+        let mut board = delayed(one_line_board());
+        board.put_block(None);
+
+        board.tick(LINE_CLEAR_DELAY, None);
+
+        assert!(board.is_spawning());
+        assert!(board.is_empty_without(&[]));
+    }
+
+    #[test]
+    fn test_spawns_after_entry_delay() {
+        let mut board = delayed(one_line_board());
+        board.put_block(None);
+
+        board.tick(LINE_CLEAR_DELAY, None);
+        board.tick(ENTRY_DELAY, None);
+
+        assert!(!board.is_spawning());
+        assert_eq!(board.block.corner.y, 0);
+    }
+
+    #[test]
+    fn test_no_line_clear_delay_without_lines() {
+        let mut board = delayed_empty_board();
+        board.put_block(None);
+
+        board.tick(ENTRY_DELAY, None);
+
+        assert!(!board.is_spawning());
+    }
+
+    #[test]
+    fn test_moves_ignored_while_spawning() {
+        let mut board = delayed_empty_board();
+        board.put_block(None);
+        let state = board.state.clone();
+
+        board.make_action(&board.keys()["left"], None);
+        board.make_action(&board.keys()["hard_drop"], None);
+
+        assert!(equals(&board.state, &state));
+    }
+
+    #[test]
+    fn test_initial_rotation() {
+        let mut board = delayed_empty_board();
+        board.put_block(None);
+
+        board.make_action(&board.keys()["rotate"], None);
+        board.tick(ENTRY_DELAY, None);
+
+        assert_eq!(board.block.rotation(), 1);
+    }
+
+    #[test]
+    fn test_initial_hold() {
+        let mut board = delayed_empty_board();
+        board.put_block(None);
+        let next_color = board.next_blocks[0].color();
+
+        board.make_action(&board.keys()["hold"], None);
+        board.tick(ENTRY_DELAY, None);
+
+        assert_eq!(
+            board.held_block().map(|block| block.color()),
+            Some(next_color)
+        );
+        assert!(!board.can_hold());
+    }
+}
@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod game_over {
+    use crate::board::game_over::GameOverReason;
+    use crate::board::tests::*;
+    use std::time::Duration;
+
+    fn hidden_rows_board(hidden_rows: u16) -> Board {
+        let config = BoardConfig {
+            hidden_rows,
+            ..BoardConfig::default()
+        };
+        Board::with_config(DEFAULT_KEYBINDINGS.clone(), config)
+    }
+
+    #[test]
+    fn test_block_out() {
+        let color_state = from_bottom_rows(&["_*********"; 18]);
+        let mut board = Board::from_data(&color_state, &get_block("yellow"), None);
+        board.draw_block();
+
+        board.put_block(None);
+
+        assert_eq!(board.game_over_reason(), Some(GameOverReason::BlockOut));
+    }
+
+    #[test]
+    fn test_lock_out() {
+        let mut board = hidden_rows_board(4);
+        board.erase_block();
+        // Stack up to the third row, with the last column left open.
+        let cols = board.state.len();
+        for col in board.state.iter_mut().take(cols - 1) {
+            for cell in col.iter_mut().skip(2) {
+                *cell = FILL_COLOR;
+            }
+        }
+        board.block = get_block("cyan");
+
+        board.put_block(None);
+
+        assert_eq!(board.game_over_reason(), Some(GameOverReason::LockOut));
+    }
+
+    #[test]
+    fn test_top_out() {
+        let mut board = hidden_rows_board(4);
+        board.receive_garbage(board.rect.height as usize);
+
+        board.put_block(None);
+
+        assert_eq!(board.game_over_reason(), Some(GameOverReason::TopOut));
+    }
+
+    #[test]
+    fn test_forfeit() {
+        let mut board = Board::default();
+
+        board.make_action(&DEFAULT_KEYBINDINGS["forfeit"], None);
+
+        assert_eq!(board.game_over_reason(), Some(GameOverReason::Forfeit));
+    }
+
+    #[test]
+    fn test_no_input_after_game_over() {
+        let mut board = Board::default();
+        board.make_action(&DEFAULT_KEYBINDINGS["forfeit"], None);
+
+        board.make_action(&DEFAULT_KEYBINDINGS["hard_drop"], None);
+
+        assert_eq!(board.score(), 0);
+        assert_eq!(board.pieces(), 0);
+    }
+
+    #[test]
+    fn test_keeps_first_reason() {
+        let mut board = Board::default();
+
+        board.end_game(GameOverReason::Forfeit);
+        board.end_game(GameOverReason::TopOut);
+
+        assert_eq!(board.game_over_reason(), Some(GameOverReason::Forfeit));
+    }
+
+    #[test]
+    fn test_clock_stops_when_game_ends() {
+        let mut board = Board::default();
+        board.tick(Duration::from_millis(300), None);
+
+        board.end_game(GameOverReason::Forfeit);
+        board.tick(Duration::from_millis(300), None);
+
+        assert_eq!(board.play_time(), Duration::from_millis(300));
+    }
+
+    #[test]
+    fn test_goal_reached() {
+        let mut board = one_line_board();
+        board.line_goal = Some(1);
+
+        board.put_block(None);
+
+        assert_eq!(board.pieces(), 1);
+        assert_eq!(board.game_over_reason(), Some(GameOverReason::GoalReached));
+    }
+}
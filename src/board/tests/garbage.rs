@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod garbage {
+    use crate::board::attack::AttackTable;
+    use crate::board::tests::*;
+
+    fn count_enemy_lines(board: &Board) -> usize {
+        (0..board.state[0].len())
+            .filter(|row| {
+                board
+                    .state
+                    .iter()
+                    .any(|col| col[*row] == board.enemy_lines_color)
+            })
+            .count()
+    }
+
+    fn single_board() -> Board {
+        let color_state = from_bottom_rows(&["*****_****"]);
+        let mut board = Board::from_data(&color_state, &get_block("cyan"), None);
+        board
+            .block
+            .rotate(&board.rect, &board.state, &board.bg_color);
+        board.block.corner.x = 3;
+        board.draw_block();
+        // Every cleared line is sent, so a single sends one line.
+        board.attack_table = AttackTable::classic();
+        board
+    }
+
+    #[test]
+    fn test_attack_is_queued() {
+        let mut board = single_board();
+        let mut other_board = Board::default();
+
+        board.put_block(Some(&mut other_board));
+
+        assert_eq!(other_board.pending_garbage(), 1);
+        assert_eq!(count_enemy_lines(&other_board), 0);
+    }
+
+    #[test]
+    fn test_inserted_after_lock_without_clear() {
+        let mut board =
+            Board::from_data(&from_char_to_color(&EMPTY_BOARD), &get_block("cyan"), None);
+        board.draw_block();
+        board.receive_garbage(2);
+        board.receive_garbage(1);
+
+        board.put_block(None);
+
+        assert_eq!(board.pending_garbage(), 0);
+        assert_eq!(count_enemy_lines(&board), 3);
+    }
+
+    #[test]
+    fn test_inserted_under_put_down_block() {
+        let mut board =
+            Board::from_data(&from_char_to_color(&EMPTY_BOARD), &get_block("cyan"), None);
+        board.draw_block();
+        board.receive_garbage(2);
+
+        board.put_block(None);
+
+        assert!((3..7).all(|x| board.state[x][17] == Color::Cyan));
+    }
+
+    #[test]
+    fn test_clear_cancels_garbage() {
+        let mut board = single_board();
+        let mut other_board = Board::default();
+        board.receive_garbage(3);
+
+        board.put_block(Some(&mut other_board));
+
+        assert_eq!(board.pending_garbage(), 2);
+        assert_eq!(count_enemy_lines(&board), 0);
+        assert_eq!(other_board.pending_garbage(), 0);
+    }
+
+    #[test]
+    fn test_attack_left_after_cancel_is_sent() {
+        let mut board = one_line_board();
+        let mut other_board = Board::default();
+        board.receive_garbage(4);
+
+        board.put_block(Some(&mut other_board));
+
+        assert_eq!(board.pending_garbage(), 0);
+        assert_eq!(other_board.pending_garbage(), 6);
+    }
+}
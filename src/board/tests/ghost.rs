@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod ghost {
+    use crate::board::tests::*;
+
+    #[test]
+    fn test_empty_board() {
+        let color_state = from_char_to_color(&EMPTY_BOARD.clone());
+        let mut board = Board::from_data(&color_state, &get_block("cyan"), None);
+        board.draw_block();
+
+        assert_eq!(
+            board.ghost_position(),
+            vec![
+                Cell { x: 3, y: 19 },
+                Cell { x: 4, y: 19 },
+                Cell { x: 5, y: 19 },
+                Cell { x: 6, y: 19 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lands_on_stack() {
+        let color_state = from_bottom_rows(&["_____*____", "**********"]);
+        let mut board = Board::from_data(&color_state, &get_block("yellow"), None);
+        board.draw_block();
+
+        assert_eq!(
+            board.ghost_position(),
+            vec![
+                Cell { x: 4, y: 16 },
+                Cell { x: 4, y: 17 },
+                Cell { x: 5, y: 16 },
+                Cell { x: 5, y: 17 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_does_not_move_block() {
+        let color_state = from_char_to_color(&EMPTY_BOARD.clone());
+        let mut board = Board::from_data(&color_state, &get_block("purple"), None);
+        board.draw_block();
+        let state_before = board.state.clone();
+
+        board.ghost_position();
+
+        assert!(equals(&state_before, &board.state));
+    }
+}
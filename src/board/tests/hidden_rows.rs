@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod hidden_rows {
+    use crate::board::tests::*;
+    use tui::{buffer::Buffer, layout::Rect, widgets::Widget};
+
+    const HIDDEN_ROWS: u16 = 4;
+
+    fn hidden_rows_board() -> Board {
+        let config = BoardConfig {
+            hidden_rows: HIDDEN_ROWS,
+            ..BoardConfig::default()
+        };
+        Board::with_config(DEFAULT_KEYBINDINGS.clone(), config)
+    }
+
+    #[test]
+    fn test_state_includes_hidden_rows() {
+        let board = hidden_rows_board();
+
+        assert_eq!(board.state[0].len(), (board.rows() + HIDDEN_ROWS) as usize);
+    }
+
+    #[test]
+    fn test_spawns_in_lowest_hidden_row() {
+        let mut board = hidden_rows_board();
+        board.erase_block();
+        board.block = get_block("purple");
+
+        board.place_at_spawn();
+
+        assert_eq!(board.block.corner.y, (HIDDEN_ROWS - 1) as i16);
+    }
+
+    #[test]
+    fn test_every_block_spawns_visible() {
+        let mut board = hidden_rows_board();
+        board.erase_block();
+
+        for name in &["cyan", "blue", "orange", "green", "red", "purple", "yellow"] {
+            board.block = get_block(name);
+            board.place_at_spawn();
+
+            let bottom = board.block.position().iter().map(|cell| cell.y).max();
+            assert_eq!(bottom, Some(HIDDEN_ROWS as i16), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_enemy_lines_push_stack_into_hidden_rows() {
+        let mut board = hidden_rows_board();
+        board.erase_block();
+        let rows = board.rows() as usize;
+        let bottom = board.rect.height as usize - 1;
+        board.state[0][bottom] = FILL_COLOR;
+
+        board.add_enemy_lines(rows);
+
+        assert_eq!(board.state[0][bottom - rows], FILL_COLOR);
+    }
+
+    #[test]
+    fn test_renders_only_visible_rows() {
+        let mut board = hidden_rows_board();
+        board.erase_block();
+        board.state[0][0] = FILL_COLOR;
+        board.state[0][HIDDEN_ROWS as usize] = Color::Red;
+        let area = Rect::new(0, 0, board.cols() * 2, board.rows());
+        let mut buffer = Buffer::empty(area);
+
+        board.render(area, &mut buffer);
+
+        assert_eq!(buffer.get(0, 0).bg, Color::Red);
+    }
+}
@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod hold {
+    use crate::board::tests::*;
+
+    #[test]
+    fn test_first_hold_stores_block() {
+        let color_state = from_char_to_color(&EMPTY_BOARD.clone());
+        let block = get_block("cyan");
+        let mut board = Board::from_data(&color_state, &block, None);
+        board.draw_block();
+
+        board.hold();
+
+        assert_eq!(board.held_block().unwrap().color(), block.color());
+        assert!(!board.can_hold());
+    }
+
+    #[test]
+    fn test_hold_resets_block() {
+        let color_state = from_char_to_color(&EMPTY_BOARD.clone());
+        let block = get_block("blue");
+        let mut board = Board::from_data(&color_state, &block, None);
+        board.draw_block();
+        board.rotate();
+        board.move_down();
+        board.move_down();
+
+        board.hold();
+
+        let held_block = board.held_block().unwrap();
+        assert_eq!(held_block.corner, block.corner);
+        assert!(equals_cells(held_block.shape(), block.shape()));
+    }
+
+    #[test]
+    fn test_hold_swaps_blocks() {
+        let color_state = from_char_to_color(&EMPTY_BOARD.clone());
+        let mut board = Board::from_data(&color_state, &get_block("cyan"), None);
+        board.draw_block();
+        board.hold();
+        board.can_hold = true;
+        board.erase_block();
+        board.block = get_block("yellow");
+        board.draw_block();
+
+        board.hold();
+
+        assert_eq!(board.block.color(), get_block("cyan").color());
+        assert_eq!(
+            board.held_block().unwrap().color(),
+            get_block("yellow").color()
+        );
+    }
+
+    #[test]
+    fn test_hold_once_per_block() {
+        let color_state = from_char_to_color(&EMPTY_BOARD.clone());
+        let mut board = Board::from_data(&color_state, &get_block("cyan"), None);
+        board.draw_block();
+        board.hold();
+        let block = board.block.clone();
+
+        board.hold();
+
+        assert_eq!(board.block.color(), block.color());
+        assert_eq!(
+            board.held_block().unwrap().color(),
+            get_block("cyan").color()
+        );
+    }
+
+    #[test]
+    fn test_put_allows_hold() {
+        let color_state = from_char_to_color(&EMPTY_BOARD.clone());
+        let mut board = Board::from_data(&color_state, &get_block("cyan"), None);
+        board.draw_block();
+        board.hold();
+
+        board.put_block(None);
+
+        assert!(board.can_hold());
+    }
+}
@@ -11,7 +11,7 @@ mod init {
 
         fill_cells(
             &mut color_state,
-            &vec![
+            &[
                 Cell { x: 3, y: 0 },
                 Cell { x: 4, y: 0 },
                 Cell { x: 5, y: 0 },
@@ -31,7 +31,7 @@ mod init {
 
         fill_cells(
             &mut color_state,
-            &vec![
+            &[
                 Cell { x: 3, y: 0 },
                 Cell { x: 3, y: 1 },
                 Cell { x: 4, y: 1 },
@@ -51,7 +51,7 @@ mod init {
 
         fill_cells(
             &mut color_state,
-            &vec![
+            &[
                 Cell { x: 3, y: 1 },
                 Cell { x: 4, y: 1 },
                 Cell { x: 5, y: 0 },
@@ -71,7 +71,7 @@ mod init {
 
         fill_cells(
             &mut color_state,
-            &vec![
+            &[
                 Cell { x: 3, y: 1 },
                 Cell { x: 4, y: 0 },
                 Cell { x: 4, y: 1 },
@@ -91,7 +91,7 @@ mod init {
 
         fill_cells(
             &mut color_state,
-            &vec![
+            &[
                 Cell { x: 3, y: 0 },
                 Cell { x: 4, y: 0 },
                 Cell { x: 4, y: 1 },
@@ -111,7 +111,7 @@ mod init {
 
         fill_cells(
             &mut color_state,
-            &vec![
+            &[
                 Cell { x: 3, y: 1 },
                 Cell { x: 4, y: 0 },
                 Cell { x: 4, y: 1 },
@@ -131,7 +131,7 @@ mod init {
 
         fill_cells(
             &mut color_state,
-            &vec![
+            &[
                 Cell { x: 4, y: 0 },
                 Cell { x: 4, y: 1 },
                 Cell { x: 5, y: 0 },
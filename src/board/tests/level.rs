@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod level {
+    use crate::board::tests::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_counts_lines() {
+        let mut board = one_line_board();
+
+        board.put_block(None);
+
+        assert_eq!(board.lines(), 1);
+        assert_eq!(board.level(), 1);
+    }
+
+    #[test]
+    fn test_level_up() {
+        let mut board = one_line_board();
+        board.lines = 9;
+
+        board.put_block(None);
+
+        assert_eq!(board.level(), 2);
+    }
+
+    #[test]
+    fn test_start_level_kept() {
+        let mut board = one_line_board();
+        board.start_level = 5;
+        board.level = 5;
+        board.lines = 9;
+
+        board.put_block(None);
+
+        assert_eq!(board.level(), 5);
+    }
+
+    #[test]
+    fn test_sub_row_gravity() {
+        let mut board = one_line_board();
+
+        board.tick(Duration::from_millis(500), None);
+        assert_eq!(board.block.corner.y, 0);
+
+        board.tick(Duration::from_millis(500), None);
+        assert_eq!(board.block.corner.y, 1);
+    }
+
+    #[test]
+    fn test_twenty_g_drops_to_floor() {
+        let color_state = from_char_to_color(&EMPTY_BOARD.clone());
+        let mut board = Board::from_data(&color_state, &get_block("cyan"), None);
+        board.draw_block();
+        board.level = 20;
+
+        board.tick(Duration::from_millis(17), None);
+
+        assert_eq!(board.block.corner.y, 19);
+    }
+}
@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod lock_delay {
+    use crate::board::tests::*;
+    use std::time::Duration;
+
+    fn on_floor_board() -> Board {
+        let color_state = from_char_to_color(&EMPTY_BOARD.clone());
+        let mut board =
+            Board::from_data(&color_state, &get_block("cyan"), Some(Cell { x: 3, y: 19 }));
+        board.draw_block();
+        board.lowest_row = 19;
+        board
+    }
+
+    /// After the block is put down a new one spawns in the top row.
+    fn is_locked(board: &Board) -> bool {
+        board.block.corner.y == 0
+    }
+
+    fn tick(board: &mut Board, millis: u64) {
+        board.tick(Duration::from_millis(millis), None);
+    }
+
+    #[test]
+    fn test_locks_after_delay() {
+        let mut board = on_floor_board();
+
+        tick(&mut board, 499);
+        assert!(!is_locked(&board));
+
+        tick(&mut board, 1);
+        assert!(is_locked(&board));
+    }
+
+    #[test]
+    fn test_move_resets_delay() {
+        let mut board = on_floor_board();
+
+        tick(&mut board, 400);
+        board.move_left();
+        tick(&mut board, 400);
+        assert!(!is_locked(&board));
+
+        tick(&mut board, 100);
+        assert!(is_locked(&board));
+    }
+
+    #[test]
+    fn test_rotate_resets_delay() {
+        let mut board = on_floor_board();
+
+        tick(&mut board, 400);
+        board.rotate_180();
+        tick(&mut board, 400);
+        assert!(!is_locked(&board));
+    }
+
+    #[test]
+    fn test_resets_are_limited() {
+        let mut board = on_floor_board();
+        board.max_lock_resets = 2;
+
+        tick(&mut board, 400);
+        board.move_left();
+        tick(&mut board, 400);
+        board.move_right();
+        tick(&mut board, 400);
+        board.move_left();
+        tick(&mut board, 100);
+
+        assert!(is_locked(&board));
+    }
+
+    #[test]
+    fn test_independent_of_gravity() {
+        let mut board = on_floor_board();
+        board.lock_delay = Duration::from_secs(2);
+
+        tick(&mut board, 1500);
+        assert!(!is_locked(&board));
+
+        tick(&mut board, 500);
+        assert!(is_locked(&board));
+    }
+}
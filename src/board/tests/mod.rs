@@ -1,11 +1,42 @@
+#[allow(clippy::module_inception)]
+mod dimensions;
+#[allow(clippy::module_inception)]
+mod drop;
+#[allow(clippy::module_inception)]
+mod entry_delay;
+#[allow(clippy::module_inception)]
+mod game_over;
+#[allow(clippy::module_inception)]
+mod garbage;
+#[allow(clippy::module_inception)]
+mod ghost;
+#[allow(clippy::module_inception)]
+mod hidden_rows;
+#[allow(clippy::module_inception)]
+mod hold;
+#[allow(clippy::module_inception)]
 mod init;
+#[allow(clippy::module_inception)]
+mod level;
+#[allow(clippy::module_inception)]
+mod lock_delay;
+#[allow(clippy::module_inception)]
 mod move_down;
+#[allow(clippy::module_inception)]
+mod next;
+#[allow(clippy::module_inception)]
+mod perfect_clear;
+#[allow(clippy::module_inception)]
+mod rotate;
+#[allow(clippy::module_inception)]
+mod seed;
+#[allow(clippy::module_inception)]
+mod spin;
 
 use crate::block::{Block, Cell};
-use crate::board::Board;
-use lazy_static;
+use crate::board::{Board, BoardConfig, DEFAULT_KEYBINDINGS};
 use std::collections::HashMap;
-use tui::layout::Rect;
+use std::time::Duration;
 use tui::style::Color;
 
 #[allow(dead_code)]
@@ -54,19 +85,23 @@ lazy_static! {
 
 impl Board {
     #[allow(dead_code)]
-    fn from_data(state: &Vec<Vec<Color>>, block: &Block, left_corner: Option<Rect>) -> Board {
-        let mut board = Board::default();
-
-        let state = state.clone();
+    fn from_data(state: &[Vec<Color>], block: &Block, left_corner: Option<Cell>) -> Board {
+        // Without hidden rows the state is exactly the visible field, and
+        // without delays the next block spawns as soon as one is put down.
+        let config = BoardConfig {
+            hidden_rows: 0,
+            line_clear_delay: Duration::default(),
+            entry_delay: Duration::default(),
+            ..BoardConfig::default()
+        };
+        let mut board = Board::with_config(DEFAULT_KEYBINDINGS.clone(), config);
+
+        let state = state.to_vec();
         board.state = state;
         board.block = block.clone();
 
-        match left_corner {
-            Some(left_corner) => {
-                board.block.rect.x = left_corner.x;
-                board.block.rect.y = left_corner.y;
-            }
-            None => (),
+        if let Some(left_corner) = left_corner {
+            board.block.corner = left_corner;
         }
 
         board
@@ -74,19 +109,19 @@ impl Board {
 }
 
 #[allow(dead_code)]
-fn from_char_to_color(state: &Vec<Vec<char>>) -> Vec<Vec<Color>> {
+fn from_char_to_color(state: &[Vec<char>]) -> Vec<Vec<Color>> {
     let mut color_state = vec![];
 
     for _ in 0..state[0].len() {
         color_state.push(Vec::new());
     }
 
-    for row in 0..state.len() {
-        for col in 0..state[row].len() {
-            if state[row][col] == FULL_BLOCK {
+    for row in state {
+        for (col, cell) in row.iter().enumerate() {
+            if *cell == FULL_BLOCK {
                 color_state[col].push(FILL_COLOR);
             } else {
-                color_state[col].push(EMPTY_COLOR.clone());
+                color_state[col].push(*EMPTY_COLOR);
             }
         }
     }
@@ -95,13 +130,13 @@ fn from_char_to_color(state: &Vec<Vec<char>>) -> Vec<Vec<Color>> {
 }
 
 #[allow(dead_code)]
-fn blocks_to_fill_color(state: &Vec<Vec<Color>>) -> Vec<Vec<Color>> {
-    let mut fill_color_state = state.clone();
+fn blocks_to_fill_color(state: &[Vec<Color>]) -> Vec<Vec<Color>> {
+    let mut fill_color_state = state.to_vec();
 
     for col in &mut fill_color_state {
         for row in col {
             if *row != *EMPTY_COLOR {
-                *row = FILL_COLOR.clone();
+                *row = FILL_COLOR;
             }
         }
     }
@@ -110,7 +145,7 @@ fn blocks_to_fill_color(state: &Vec<Vec<Color>>) -> Vec<Vec<Color>> {
 }
 
 #[allow(dead_code)]
-fn equals(first: &Vec<Vec<Color>>, second: &Vec<Vec<Color>>) -> bool {
+fn equals(first: &[Vec<Color>], second: &[Vec<Color>]) -> bool {
     if first.len() != second.len() {
         return false;
     }
@@ -124,13 +159,13 @@ fn equals(first: &Vec<Vec<Color>>, second: &Vec<Vec<Color>>) -> bool {
             }
         }
     }
-    return true;
+    true
 }
 
 #[allow(dead_code)]
-fn fill_cells(state: &mut Vec<Vec<Color>>, cells: &Vec<Cell>) {
+fn fill_cells(state: &mut [Vec<Color>], cells: &[Cell]) {
     for cell in cells {
-        state[cell.x as usize][cell.y as usize] = FILL_COLOR.clone();
+        state[cell.x as usize][cell.y as usize] = FILL_COLOR;
     }
 }
 
@@ -138,3 +173,35 @@ fn fill_cells(state: &mut Vec<Vec<Color>>, cells: &Vec<Cell>) {
 fn get_block(block: &str) -> Block {
     TETRIS_BLOCKS.get(&block.to_string()).unwrap().clone()
 }
+
+#[allow(dead_code)]
+fn equals_cells(first: &[Cell], second: &[Cell]) -> bool {
+    first.len() == second.len()
+        && first
+            .iter()
+            .zip(second)
+            .all(|(a, b)| a.x == b.x && a.y == b.y)
+}
+
+/// A cyan block over a bottom row it completes, putting it down clears one
+/// line and leaves the board empty.
+#[allow(dead_code)]
+fn one_line_board() -> Board {
+    let color_state = from_bottom_rows(&["***____***"]);
+    let mut board = Board::from_data(&color_state, &get_block("cyan"), None);
+    board.draw_block();
+    board
+}
+
+/// Builds a state from the given rows placed at the bottom of an empty board.
+#[allow(dead_code)]
+fn from_bottom_rows(rows: &[&str]) -> Vec<Vec<Color>> {
+    let mut state = EMPTY_BOARD.clone();
+    let offset = state.len() - rows.len();
+
+    for (row_index, row) in rows.iter().enumerate() {
+        state[offset + row_index] = row.chars().collect();
+    }
+
+    from_char_to_color(&state)
+}
@@ -5,7 +5,7 @@ mod move_down {
     mod can_perform {
         use super::*;
 
-        fn test_can_perform(block_color: &str, cells_to_fill: &Vec<Cell>) {
+        fn test_can_perform(block_color: &str, cells_to_fill: &[Cell]) {
             let mut color_state = from_char_to_color(&EMPTY_BOARD.clone());
             let block = get_block(block_color);
             let mut board = Board::from_data(&color_state, &block, None);
@@ -19,7 +19,7 @@ mod move_down {
         fn test_cyan() {
             test_can_perform(
                 "cyan",
-                &vec![
+                &[
                     Cell { x: 3, y: 1 },
                     Cell { x: 4, y: 1 },
                     Cell { x: 5, y: 1 },
@@ -32,7 +32,7 @@ mod move_down {
         fn test_blue() {
             test_can_perform(
                 "blue",
-                &vec![
+                &[
                     Cell { x: 3, y: 1 },
                     Cell { x: 3, y: 2 },
                     Cell { x: 4, y: 2 },
@@ -45,7 +45,7 @@ mod move_down {
         fn test_orange() {
             test_can_perform(
                 "orange",
-                &vec![
+                &[
                     Cell { x: 3, y: 2 },
                     Cell { x: 4, y: 2 },
                     Cell { x: 5, y: 1 },
@@ -58,7 +58,7 @@ mod move_down {
         fn test_green() {
             test_can_perform(
                 "green",
-                &vec![
+                &[
                     Cell { x: 3, y: 2 },
                     Cell { x: 4, y: 1 },
                     Cell { x: 4, y: 2 },
@@ -71,7 +71,7 @@ mod move_down {
         fn test_red() {
             test_can_perform(
                 "red",
-                &vec![
+                &[
                     Cell { x: 3, y: 1 },
                     Cell { x: 4, y: 1 },
                     Cell { x: 4, y: 2 },
@@ -84,7 +84,7 @@ mod move_down {
         fn test_purple() {
             test_can_perform(
                 "purple",
-                &vec![
+                &[
                     Cell { x: 3, y: 2 },
                     Cell { x: 4, y: 1 },
                     Cell { x: 4, y: 2 },
@@ -97,7 +97,7 @@ mod move_down {
         fn test_yellow() {
             test_can_perform(
                 "yellow",
-                &vec![
+                &[
                     Cell { x: 4, y: 1 },
                     Cell { x: 4, y: 2 },
                     Cell { x: 5, y: 1 },
@@ -110,10 +110,10 @@ mod move_down {
     mod cannot_perform {
         use super::*;
 
-        fn test_cannot_perform_bottom(block_color: &str, rect: Rect) {
+        fn test_cannot_perform_bottom(block_color: &str, corner: Cell) {
             let color_state = from_char_to_color(&EMPTY_BOARD.clone());
             let block = get_block(block_color);
-            let mut board = Board::from_data(&color_state, &block, Some(rect));
+            let mut board = Board::from_data(&color_state, &block, Some(corner));
 
             board.draw_block();
             let state_before = board.state.clone();
@@ -128,93 +128,37 @@ mod move_down {
         }
         #[test]
         fn test_cyan() {
-            test_cannot_perform_bottom(
-                "cyan",
-                Rect {
-                    x: 3,
-                    y: 19,
-                    width: 4,
-                    height: 1,
-                },
-            );
+            test_cannot_perform_bottom("cyan", Cell { x: 3, y: 19 });
         }
 
         #[test]
         fn test_blue() {
-            test_cannot_perform_bottom(
-                "blue",
-                Rect {
-                    x: 3,
-                    y: 18,
-                    width: 3,
-                    height: 2,
-                },
-            );
+            test_cannot_perform_bottom("blue", Cell { x: 3, y: 18 });
         }
 
         #[test]
         fn test_orange() {
-            test_cannot_perform_bottom(
-                "orange",
-                Rect {
-                    x: 3,
-                    y: 18,
-                    width: 3,
-                    height: 2,
-                },
-            );
+            test_cannot_perform_bottom("orange", Cell { x: 3, y: 18 });
         }
 
         #[test]
         fn test_green() {
-            test_cannot_perform_bottom(
-                "green",
-                Rect {
-                    x: 3,
-                    y: 18,
-                    width: 3,
-                    height: 2,
-                },
-            );
+            test_cannot_perform_bottom("green", Cell { x: 3, y: 18 });
         }
 
         #[test]
         fn test_red() {
-            test_cannot_perform_bottom(
-                "red",
-                Rect {
-                    x: 3,
-                    y: 18,
-                    width: 3,
-                    height: 2,
-                },
-            );
+            test_cannot_perform_bottom("red", Cell { x: 3, y: 18 });
         }
 
         #[test]
         fn test_purple() {
-            test_cannot_perform_bottom(
-                "purple",
-                Rect {
-                    x: 3,
-                    y: 18,
-                    width: 3,
-                    height: 2,
-                },
-            );
+            test_cannot_perform_bottom("purple", Cell { x: 3, y: 18 });
         }
 
         #[test]
         fn test_yellow() {
-            test_cannot_perform_bottom(
-                "yellow",
-                Rect {
-                    x: 4,
-                    y: 18,
-                    width: 2,
-                    height: 2,
-                },
-            );
+            test_cannot_perform_bottom("yellow", Cell { x: 4, y: 18 });
         }
     }
 }
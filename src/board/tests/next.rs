@@ -0,0 +1,37 @@
+#[cfg(test)]
+mod next {
+    use crate::board::tests::*;
+    use crate::board::{BoardConfig, DEFAULT_KEYBINDINGS, DEFAULT_PREVIEW_LENGTH};
+
+    #[test]
+    fn test_default_preview_length() {
+        let board = Board::default();
+
+        assert_eq!(board.next_blocks().len(), DEFAULT_PREVIEW_LENGTH);
+    }
+
+    #[test]
+    fn test_configured_preview_length() {
+        let config = BoardConfig {
+            preview_length: 2,
+            ..BoardConfig::default()
+        };
+        let board = Board::with_config(DEFAULT_KEYBINDINGS.clone(), config);
+
+        assert_eq!(board.next_blocks().len(), 2);
+    }
+
+    #[test]
+    fn test_put_takes_first_next_block() {
+        let color_state = from_char_to_color(&EMPTY_BOARD.clone());
+        let mut board = Board::from_data(&color_state, &get_block("cyan"), None);
+        board.draw_block();
+        let next_blocks = board.next_blocks();
+
+        board.put_block(None);
+
+        assert_eq!(board.block.color(), next_blocks[0].color());
+        assert_eq!(board.next_blocks().len(), next_blocks.len());
+        assert_eq!(board.next_blocks()[0].color(), next_blocks[1].color());
+    }
+}
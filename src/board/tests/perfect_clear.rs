@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod perfect_clear {
+    use crate::board::tests::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_scores_bonus() {
+        let mut board = one_line_board();
+
+        board.put_block(None);
+
+        assert_eq!(board.score(), 100 + 800);
+        assert_eq!(board.callout, Some("PERFECT CLEAR".to_string()));
+    }
+
+    #[test]
+    fn test_sends_attack() {
+        let mut board = one_line_board();
+        let mut other_board = Board::default();
+
+        board.put_block(Some(&mut other_board));
+
+        assert_eq!(other_board.pending_garbage(), 10);
+    }
+
+    #[test]
+    fn test_not_empty() {
+        let color_state = from_bottom_rows(&["*_________", "***____***"]);
+        let mut board = Board::from_data(&color_state, &get_block("cyan"), None);
+        board.draw_block();
+
+        board.put_block(None);
+
+        assert_eq!(board.score(), 100);
+        assert_eq!(board.callout, None);
+    }
+
+    #[test]
+    fn test_callout_disappears() {
+        let mut board = one_line_board();
+        board.put_block(None);
+
+        board.tick(Duration::from_secs(2), None);
+
+        assert_eq!(board.callout, None);
+    }
+}
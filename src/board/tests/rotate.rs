@@ -0,0 +1,263 @@
+#[cfg(test)]
+mod rotate {
+    use crate::board::tests::*;
+
+    fn test_rotate(
+        state: &[Vec<Color>],
+        block_color: &str,
+        corner: Cell,
+        rotations: usize,
+        expected: &[Cell],
+    ) {
+        let mut board = Board::from_data(state, &get_block(block_color), Some(corner));
+        board.draw_block();
+        for _ in 0..rotations {
+            board.rotate();
+        }
+
+        assert_eq!(&board.block.position(), expected);
+    }
+
+    #[test]
+    fn test_purple_without_kick() {
+        test_rotate(
+            &from_char_to_color(&EMPTY_BOARD.clone()),
+            "purple",
+            Cell { x: 3, y: 5 },
+            1,
+            &[
+                Cell { x: 4, y: 5 },
+                Cell { x: 4, y: 6 },
+                Cell { x: 4, y: 7 },
+                Cell { x: 5, y: 6 },
+            ],
+        );
+    }
+
+    #[test]
+    fn test_full_turn_returns_to_spawn() {
+        for block_color in &["cyan", "blue", "orange", "green", "red", "purple", "yellow"] {
+            let corner = Cell { x: 3, y: 5 };
+            let mut block = get_block(block_color);
+            block.corner = corner;
+
+            test_rotate(
+                &from_char_to_color(&EMPTY_BOARD.clone()),
+                block_color,
+                corner,
+                4,
+                &block.position(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_cyan_left_wall_kick() {
+        // Vertical in the first column, state L -> 0 uses the (+1, 0) kick.
+        let mut board = Board::from_data(
+            &from_char_to_color(&EMPTY_BOARD.clone()),
+            &get_block("cyan"),
+            Some(Cell { x: 3, y: 5 }),
+        );
+        board.draw_block();
+        board.rotate();
+        board.rotate();
+        board.rotate();
+        while board.block.corner.x > -1 {
+            board.move_left();
+        }
+        assert_eq!(board.block.position()[0], Cell { x: 0, y: 4 });
+
+        board.rotate();
+
+        assert_eq!(
+            board.block.position(),
+            vec![
+                Cell { x: 0, y: 5 },
+                Cell { x: 1, y: 5 },
+                Cell { x: 2, y: 5 },
+                Cell { x: 3, y: 5 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cyan_right_wall_kick() {
+        // Vertical in the last column, state R -> 2 uses the (-1, 0) kick.
+        let mut board = Board::from_data(
+            &from_char_to_color(&EMPTY_BOARD.clone()),
+            &get_block("cyan"),
+            Some(Cell { x: 3, y: 5 }),
+        );
+        board.draw_block();
+        board.rotate();
+        while board.block.corner.x < 7 {
+            board.move_right();
+        }
+        assert_eq!(board.block.position()[0], Cell { x: 9, y: 4 });
+
+        board.rotate();
+
+        assert_eq!(
+            board.block.position(),
+            vec![
+                Cell { x: 6, y: 6 },
+                Cell { x: 7, y: 6 },
+                Cell { x: 8, y: 6 },
+                Cell { x: 9, y: 6 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_purple_triple_kick() {
+        // The first four kicks of 0 -> R are blocked, the last one (-1, +2)
+        // twists the block into the slot.
+        test_rotate(
+            &from_bottom_rows(&[
+                "****______",
+                "***_______",
+                "***_******",
+                "***__*****",
+                "***_******",
+            ]),
+            "purple",
+            Cell { x: 3, y: 15 },
+            1,
+            &[
+                Cell { x: 3, y: 17 },
+                Cell { x: 3, y: 18 },
+                Cell { x: 3, y: 19 },
+                Cell { x: 4, y: 18 },
+            ],
+        );
+    }
+
+    #[test]
+    fn test_cyan_all_kicks_blocked() {
+        test_rotate(
+            &from_bottom_rows(&["**********", "***____***"]),
+            "cyan",
+            Cell { x: 3, y: 19 },
+            1,
+            &[
+                Cell { x: 3, y: 19 },
+                Cell { x: 4, y: 19 },
+                Cell { x: 5, y: 19 },
+                Cell { x: 6, y: 19 },
+            ],
+        );
+    }
+
+    #[test]
+    fn test_counter_clockwise_undoes_clockwise() {
+        for block_color in &["cyan", "blue", "orange", "green", "red", "purple", "yellow"] {
+            let mut board = Board::from_data(
+                &from_char_to_color(&EMPTY_BOARD.clone()),
+                &get_block(block_color),
+                Some(Cell { x: 3, y: 5 }),
+            );
+            board.draw_block();
+            let before = board.block.position();
+
+            board.rotate();
+            board.rotate_counter_clockwise();
+
+            assert_eq!(board.block.position(), before);
+        }
+    }
+
+    #[test]
+    fn test_purple_counter_clockwise() {
+        let mut board = Board::from_data(
+            &from_char_to_color(&EMPTY_BOARD.clone()),
+            &get_block("purple"),
+            Some(Cell { x: 3, y: 5 }),
+        );
+        board.draw_block();
+
+        board.rotate_counter_clockwise();
+
+        assert_eq!(
+            board.block.position(),
+            vec![
+                Cell { x: 3, y: 6 },
+                Cell { x: 4, y: 5 },
+                Cell { x: 4, y: 6 },
+                Cell { x: 4, y: 7 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cyan_counter_clockwise_right_wall_kick() {
+        // Vertical in the last column, state R -> 0 uses the (-1, 0) kick.
+        let mut board = Board::from_data(
+            &from_char_to_color(&EMPTY_BOARD.clone()),
+            &get_block("cyan"),
+            Some(Cell { x: 3, y: 5 }),
+        );
+        board.draw_block();
+        board.rotate();
+        while board.block.corner.x < 7 {
+            board.move_right();
+        }
+
+        board.rotate_counter_clockwise();
+
+        assert_eq!(
+            board.block.position(),
+            vec![
+                Cell { x: 6, y: 5 },
+                Cell { x: 7, y: 5 },
+                Cell { x: 8, y: 5 },
+                Cell { x: 9, y: 5 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_purple_180() {
+        let mut board = Board::from_data(
+            &from_char_to_color(&EMPTY_BOARD.clone()),
+            &get_block("purple"),
+            Some(Cell { x: 3, y: 5 }),
+        );
+        board.draw_block();
+
+        board.rotate_180();
+
+        assert_eq!(
+            board.block.position(),
+            vec![
+                Cell { x: 3, y: 6 },
+                Cell { x: 4, y: 6 },
+                Cell { x: 4, y: 7 },
+                Cell { x: 5, y: 6 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_purple_180_floor_kick() {
+        // On the floor the block is kicked one row up.
+        let mut board = Board::from_data(
+            &from_char_to_color(&EMPTY_BOARD.clone()),
+            &get_block("purple"),
+            Some(Cell { x: 3, y: 18 }),
+        );
+        board.draw_block();
+
+        board.rotate_180();
+
+        assert_eq!(
+            board.block.position(),
+            vec![
+                Cell { x: 3, y: 18 },
+                Cell { x: 4, y: 18 },
+                Cell { x: 4, y: 19 },
+                Cell { x: 5, y: 18 },
+            ]
+        );
+    }
+}
@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod seed {
+    use crate::board::tests::*;
+
+    fn seeded_board(seed: u64) -> Board {
+        let config = BoardConfig {
+            seed: Some(seed),
+            line_clear_delay: Duration::default(),
+            entry_delay: Duration::default(),
+            ..BoardConfig::default()
+        };
+        Board::with_config(DEFAULT_KEYBINDINGS.clone(), config)
+    }
+
+    fn colors(blocks: &[Block]) -> Vec<Color> {
+        blocks.iter().map(|block| block.color()).collect()
+    }
+
+    #[test]
+    fn test_same_seed_same_blocks() {
+        let first = seeded_board(42);
+        let second = seeded_board(42);
+
+        assert_eq!(first.seed(), 42);
+        assert_eq!(first.block.color(), second.block.color());
+        assert_eq!(colors(&first.next_blocks()), colors(&second.next_blocks()));
+    }
+
+    #[test]
+    fn test_same_seed_same_inputs_same_game() {
+        let play = |board: &mut Board| {
+            let put = board.keys()["hard_drop"];
+            let left = board.keys()["left"];
+            for turn in 0..12 {
+                if turn % 3 == 0 {
+                    board.receive_garbage(2);
+                }
+                board.make_action(&left, None);
+                board.make_action(&put, None);
+            }
+        };
+        let mut first = seeded_board(7);
+        let mut second = seeded_board(7);
+
+        play(&mut first);
+        play(&mut second);
+
+        assert!(equals(&first.state, &second.state));
+        assert_eq!(colors(&first.next_blocks()), colors(&second.next_blocks()));
+    }
+
+    #[test]
+    fn test_random_seed_is_kept() {
+        let board = Board::default();
+        let replay = seeded_board(board.seed());
+
+        assert_eq!(colors(&board.next_blocks()), colors(&replay.next_blocks()));
+    }
+
+    #[test]
+    fn test_garbage_does_not_change_blocks() {
+        let mut first = seeded_board(3);
+        let mut second = seeded_board(3);
+        second.receive_garbage(4);
+        let put = first.keys()["hard_drop"];
+
+        for _ in 0..5 {
+            first.make_action(&put, None);
+            second.make_action(&put, None);
+        }
+
+        assert!(!second.has_game_ended());
+        assert_eq!(first.block.color(), second.block.color());
+        assert_eq!(colors(&first.next_blocks()), colors(&second.next_blocks()));
+    }
+}
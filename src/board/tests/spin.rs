@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod spin {
+    use crate::board::spin::{detect, Spin};
+    use crate::board::tests::*;
+    use crate::board::Rotation;
+
+    fn detect_spin(board: &Board) -> Spin {
+        detect(
+            &board.block,
+            board.last_kick,
+            &board.rect,
+            &board.state,
+            &board.bg_color,
+        )
+    }
+
+    fn t_spin_double_board() -> Board {
+        let color_state = from_bottom_rows(&["****______", "***___****", "****_*****"]);
+        let mut board = Board::from_data(
+            &color_state,
+            &get_block("purple"),
+            Some(Cell { x: 3, y: 17 }),
+        );
+        board.draw_block();
+        board
+    }
+
+    #[test]
+    fn test_t_spin_double() {
+        let mut board = t_spin_double_board();
+        board.rotate_180();
+
+        assert_eq!(detect_spin(&board), Spin::Full);
+        assert_eq!(board.put_block(None), 2);
+        assert_eq!(board.score(), 1200);
+    }
+
+    #[test]
+    fn test_no_rotation() {
+        let mut board = t_spin_double_board();
+        board.rotate_180();
+        board.last_kick = None;
+
+        assert_eq!(detect_spin(&board), Spin::None);
+    }
+
+    #[test]
+    fn test_move_forgets_rotation() {
+        let color_state = from_char_to_color(&EMPTY_BOARD.clone());
+        let mut board = Board::from_data(&color_state, &get_block("purple"), None);
+        board.draw_block();
+        board.rotate();
+        assert_eq!(board.last_kick, Some((Rotation::Clockwise, 0)));
+
+        board.move_left();
+
+        assert_eq!(board.last_kick, None);
+    }
+
+    #[test]
+    fn test_mini() {
+        let color_state = from_bottom_rows(&["*_________", "__________"]);
+        let mut board = Board::from_data(
+            &color_state,
+            &get_block("purple"),
+            Some(Cell { x: 0, y: 18 }),
+        );
+        board.draw_block();
+        board.last_kick = Some((Rotation::Clockwise, 0));
+
+        assert_eq!(detect_spin(&board), Spin::Mini);
+    }
+
+    #[test]
+    fn test_last_kick_is_full() {
+        let mut board = Board::from_data(
+            &from_bottom_rows(&[
+                "****______",
+                "***_______",
+                "***_******",
+                "***__*****",
+                "***_******",
+            ]),
+            &get_block("purple"),
+            Some(Cell { x: 3, y: 15 }),
+        );
+        board.draw_block();
+        board.rotate();
+
+        assert_eq!(board.last_kick, Some((Rotation::Clockwise, 4)));
+        assert_eq!(detect_spin(&board), Spin::Full);
+    }
+
+    #[test]
+    fn test_half_turn_last_kick_is_not_full() {
+        let mut board = Board::from_data(
+            &from_bottom_rows(&[
+                "__*_______",
+                "__________",
+                "*_*_______",
+                "*_________",
+                "__________",
+                "__________",
+                "__________",
+            ]),
+            &get_block("purple"),
+            Some(Cell { x: 0, y: 15 }),
+        );
+        // Point right, so the half turn has to use its fifth kick.
+        board
+            .block
+            .rotate(&board.rect, &board.state, &board.bg_color);
+        board.draw_block();
+        board.rotate_180();
+
+        assert_eq!(board.last_kick, Some((Rotation::Half, 4)));
+        assert_eq!(detect_spin(&board), Spin::Mini);
+    }
+
+    #[test]
+    fn test_other_blocks_do_not_spin() {
+        let color_state = from_bottom_rows(&["*_________", "__________"]);
+        let mut board =
+            Board::from_data(&color_state, &get_block("blue"), Some(Cell { x: 0, y: 18 }));
+        board.draw_block();
+        board.last_kick = Some((Rotation::Clockwise, 0));
+
+        assert_eq!(detect_spin(&board), Spin::None);
+    }
+}
@@ -8,11 +8,16 @@ use std::sync::{
 use std::thread;
 use std::time::Duration;
 
-use termion::event::Key;
+use termion::event::{Event as TermionEvent, Key};
 use termion::input::TermRead;
 
+use crate::input::{parse_kitty_key, KeyAction};
+
 pub enum Event<I> {
+    /// A key press, or a repeat of a held key.
     Input(I),
+    /// A key release, only sent by terminals with the kitty keyboard protocol.
+    Release(I),
     Tick,
 }
 
@@ -55,15 +60,28 @@ impl Events {
             let ignore_exit_key = ignore_exit_key.clone();
             thread::spawn(move || {
                 let stdin = io::stdin();
-                for evt in stdin.keys() {
-                    if let Ok(key) = evt {
-                        if let Err(err) = tx.send(Event::Input(key)) {
-                            eprintln!("{}", err);
-                            return;
-                        }
-                        if !ignore_exit_key.load(Ordering::Relaxed) && key == config.exit_key {
-                            return;
-                        }
+                for event in stdin.events().flatten() {
+                    let (key, action) = match event {
+                        TermionEvent::Key(key) => (key, KeyAction::Press),
+                        TermionEvent::Unsupported(bytes) => match parse_kitty_key(&bytes) {
+                            Some(key_action) => key_action,
+                            None => continue,
+                        },
+                        _ => continue,
+                    };
+                    let event = match action {
+                        KeyAction::Press | KeyAction::Repeat => Event::Input(key),
+                        KeyAction::Release => Event::Release(key),
+                    };
+                    if let Err(err) = tx.send(event) {
+                        eprintln!("{}", err);
+                        return;
+                    }
+                    if !ignore_exit_key.load(Ordering::Relaxed)
+                        && key == config.exit_key
+                        && action == KeyAction::Press
+                    {
+                        return;
                     }
                 }
             })
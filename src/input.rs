@@ -0,0 +1,398 @@
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use termion::event::Key;
+
+pub const DEFAULT_DAS: Duration = Duration::from_millis(167);
+pub const DEFAULT_ARR: Duration = Duration::from_millis(33);
+
+// Kitty keyboard protocol flags: disambiguate keys (1), report repeats and
+// releases (2) and report every key as an escape code (8), so keys that type
+// text get release events too.
+const ENABLE_KEY_RELEASES: &[u8] = b"\x1b[>11u";
+const DISABLE_KEY_RELEASES: &[u8] = b"\x1b[<u";
+
+// Without release events a key counts as held once the terminal has repeated
+// it twice in a row faster than this, a single short gap may be a double tap.
+const HOLD_GAP: Duration = Duration::from_millis(100);
+// Without release events a held key is released once the terminal stops
+// repeating it for this long.
+const RELEASE_TIMEOUT: Duration = Duration::from_millis(100);
+// Longest delay before terminals start repeating a held key.
+const MAX_REPEAT_DELAY: Duration = Duration::from_millis(700);
+// Repeats sent at once with a zero repeat rate, enough to reach any wall.
+const INSTANT_REPEATS: u32 = 64;
+
+const SHIFT: u8 = 1;
+const ALT: u8 = 2;
+const CTRL: u8 = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum KeyAction {
+    Press,
+    Repeat,
+    Release,
+}
+
+/// Keeps the terminal sending release events while it lives, like termion's
+/// `RawTerminal`, so the shell gets plain keys back however the game exits.
+/// Terminals without the kitty keyboard protocol ignore it and keep sending
+/// plain keys.
+pub struct KeyReleases {
+    out: Box<dyn Write>,
+}
+
+impl KeyReleases {
+    pub fn enable(mut out: Box<dyn Write>) -> io::Result<KeyReleases> {
+        out.write_all(ENABLE_KEY_RELEASES)?;
+        out.flush()?;
+        Ok(KeyReleases { out })
+    }
+}
+
+impl Drop for KeyReleases {
+    fn drop(&mut self) {
+        let _ = self.out.write_all(DISABLE_KEY_RELEASES);
+        let _ = self.out.flush();
+    }
+}
+
+/// Parses a kitty keyboard protocol sequence, like `ESC [ 97 ; 1 : 3 u` for
+/// releasing 'a', which termion reports as unsupported.
+pub fn parse_kitty_key(bytes: &[u8]) -> Option<(Key, KeyAction)> {
+    let sequence = std::str::from_utf8(bytes.strip_prefix(b"\x1b[")?).ok()?;
+    let final_char = sequence.chars().last()?;
+    let params = &sequence[..sequence.len() - final_char.len_utf8()];
+
+    let mut fields = params.split(';');
+    let code: u32 = match fields.next()?.split(':').next()? {
+        "" => 1,
+        code => code.parse().ok()?,
+    };
+    let mut modifier_fields = fields.next().unwrap_or("1").split(':');
+    let modifiers = modifier_fields
+        .next()?
+        .parse::<u8>()
+        .ok()?
+        .saturating_sub(1);
+    let action = match modifier_fields.next().unwrap_or("1") {
+        "1" => KeyAction::Press,
+        "2" => KeyAction::Repeat,
+        "3" => KeyAction::Release,
+        _ => return None,
+    };
+
+    let key = match (final_char, code) {
+        ('A', 1) => Key::Up,
+        ('B', 1) => Key::Down,
+        ('C', 1) => Key::Right,
+        ('D', 1) => Key::Left,
+        ('u', 9) => Key::Char('\t'),
+        ('u', 13) => Key::Char('\n'),
+        ('u', 27) => Key::Esc,
+        ('u', 127) => Key::Backspace,
+        ('u', code) => {
+            let c = std::char::from_u32(code)?;
+            if modifiers & CTRL != 0 {
+                Key::Ctrl(c)
+            } else if modifiers & ALT != 0 {
+                Key::Alt(c)
+            } else if modifiers & SHIFT != 0 {
+                Key::Char(c.to_ascii_uppercase())
+            } else {
+                Key::Char(c)
+            }
+        }
+        _ => return None,
+    };
+    Some((key, action))
+}
+
+#[derive(Debug, Clone)]
+struct HeldKey {
+    key: Key,
+    pressed_at: Instant,
+    last_press: Instant,
+    // False until the key is known to be held rather than tapped.
+    is_held: bool,
+    // The last gap between presses was short enough to be a repeat.
+    had_short_gap: bool,
+    // Repeats sent so far.
+    repeats: u32,
+}
+
+/// Delayed auto shift (DAS) and auto repeat rate (ARR) for held keys.
+///
+/// With release events a key is held from its press to its release. Without
+/// them a key counts as held once the terminal's own repeats arrive, and as
+/// released when they stop, so DAS can't be shorter than the terminal's
+/// repeat delay.
+#[derive(Debug, Clone)]
+pub struct AutoRepeat {
+    das: Duration,
+    arr: Duration,
+    // Keys that repeat while held, others fire once per press.
+    repeat_keys: Vec<Key>,
+    held: Vec<HeldKey>,
+    has_release_events: bool,
+}
+
+impl AutoRepeat {
+    pub fn new(das: Duration, arr: Duration, repeat_keys: Vec<Key>) -> AutoRepeat {
+        AutoRepeat {
+            das,
+            arr,
+            repeat_keys,
+            held: Vec::new(),
+            has_release_events: false,
+        }
+    }
+
+    /// Handles a key press or a repeat from the terminal. Returns true if the
+    /// key's action should run, repeats of held keys only come from `tick`.
+    pub fn press(&mut self, key: Key, now: Instant) -> bool {
+        let has_release_events = self.has_release_events;
+        let held = match self.held.iter_mut().find(|held| held.key == key) {
+            Some(held) => held,
+            None => {
+                self.held.push(HeldKey {
+                    key,
+                    pressed_at: now,
+                    last_press: now,
+                    is_held: has_release_events,
+                    had_short_gap: false,
+                    repeats: 0,
+                });
+                return true;
+            }
+        };
+
+        let gap = now.duration_since(held.last_press);
+        held.last_press = now;
+        if has_release_events || held.is_held {
+            return false;
+        }
+        if gap > HOLD_GAP {
+            held.had_short_gap = false;
+        } else if held.had_short_gap {
+            // The terminal is repeating the key, take over from here without
+            // sending the repeats it would have made so far.
+            held.is_held = true;
+            held.repeats = due_repeats(held, now, self.das, self.arr);
+            return false;
+        } else {
+            held.had_short_gap = true;
+        }
+        // Either the terminal's first repeats or more taps.
+        true
+    }
+
+    pub fn release(&mut self, key: Key) {
+        self.has_release_events = true;
+        self.held.retain(|held| held.key != key);
+    }
+
+    /// Keys whose action should run again because they are held.
+    pub fn tick(&mut self, now: Instant) -> Vec<Key> {
+        if !self.has_release_events {
+            self.held.retain(|held| {
+                let timeout = if held.is_held {
+                    RELEASE_TIMEOUT
+                } else {
+                    MAX_REPEAT_DELAY
+                };
+                now.duration_since(held.last_press) <= timeout
+            });
+        }
+
+        let mut keys = vec![];
+        for held in &mut self.held {
+            if !held.is_held || !self.repeat_keys.contains(&held.key) {
+                continue;
+            }
+            let due = due_repeats(held, now, self.das, self.arr);
+            for _ in held.repeats..due {
+                keys.push(held.key);
+            }
+            held.repeats = held.repeats.max(due);
+        }
+        keys
+    }
+
+    /// True if the key is currently down. Without release events a tapped
+    /// key counts as down until `RELEASE_TIMEOUT` has passed.
+    pub fn is_pressed(&self, key: Key, now: Instant) -> bool {
+        self.held.iter().any(|held| {
+            held.key == key
+                && (self.has_release_events
+                    || held.is_held
+                    || now.duration_since(held.last_press) <= RELEASE_TIMEOUT)
+        })
+    }
+}
+
+/// Number of repeats a key held since `pressed_at` should have sent by `now`.
+fn due_repeats(held: &HeldKey, now: Instant, das: Duration, arr: Duration) -> u32 {
+    let held_for = now.duration_since(held.pressed_at);
+    if held_for < das {
+        0
+    } else if arr == Duration::default() {
+        INSTANT_REPEATS
+    } else {
+        ((held_for - das).as_nanos() / arr.as_nanos()) as u32 + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAS: Duration = Duration::from_millis(150);
+    const ARR: Duration = Duration::from_millis(50);
+
+    fn auto_repeat() -> AutoRepeat {
+        AutoRepeat::new(DAS, ARR, vec![Key::Left])
+    }
+
+    fn millis(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_parse_kitty_press_and_release() {
+        assert_eq!(
+            parse_kitty_key(b"\x1b[97u"),
+            Some((Key::Char('a'), KeyAction::Press))
+        );
+        assert_eq!(
+            parse_kitty_key(b"\x1b[97;1:3u"),
+            Some((Key::Char('a'), KeyAction::Release))
+        );
+        assert_eq!(
+            parse_kitty_key(b"\x1b[1;1:2D"),
+            Some((Key::Left, KeyAction::Repeat))
+        );
+        assert_eq!(
+            parse_kitty_key(b"\x1b[127;1:3u"),
+            Some((Key::Backspace, KeyAction::Release))
+        );
+    }
+
+    #[test]
+    fn test_parse_kitty_modifiers() {
+        assert_eq!(
+            parse_kitty_key(b"\x1b[99;5u"),
+            Some((Key::Ctrl('c'), KeyAction::Press))
+        );
+        assert_eq!(
+            parse_kitty_key(b"\x1b[97;2u"),
+            Some((Key::Char('A'), KeyAction::Press))
+        );
+    }
+
+    #[test]
+    fn test_parse_not_kitty() {
+        assert_eq!(parse_kitty_key(b"\x1b[2;5~"), None);
+        assert_eq!(parse_kitty_key(b"a"), None);
+    }
+
+    #[test]
+    fn test_das_then_arr_with_releases() {
+        let start = Instant::now();
+        let mut auto_repeat = auto_repeat();
+        auto_repeat.release(Key::Right);
+
+        assert!(auto_repeat.press(Key::Left, start));
+        assert!(auto_repeat.tick(start + millis(100)).is_empty());
+        assert_eq!(auto_repeat.tick(start + millis(150)), vec![Key::Left]);
+        assert_eq!(auto_repeat.tick(start + millis(260)).len(), 2);
+
+        auto_repeat.release(Key::Left);
+
+        assert!(auto_repeat.tick(start + millis(400)).is_empty());
+    }
+
+    #[test]
+    fn test_terminal_repeats_ignored_while_held() {
+        let start = Instant::now();
+        let mut auto_repeat = auto_repeat();
+        auto_repeat.release(Key::Right);
+        auto_repeat.press(Key::Left, start);
+
+        assert!(!auto_repeat.press(Key::Left, start + millis(30)));
+    }
+
+    #[test]
+    fn test_other_keys_do_not_repeat() {
+        let start = Instant::now();
+        let mut auto_repeat = auto_repeat();
+        auto_repeat.release(Key::Right);
+        auto_repeat.press(Key::Up, start);
+
+        assert!(auto_repeat.tick(start + millis(500)).is_empty());
+        assert!(!auto_repeat.press(Key::Up, start + millis(500)));
+    }
+
+    #[test]
+    fn test_zero_arr_repeats_instantly() {
+        let start = Instant::now();
+        let mut auto_repeat = AutoRepeat::new(DAS, Duration::default(), vec![Key::Left]);
+        auto_repeat.release(Key::Right);
+        auto_repeat.press(Key::Left, start);
+
+        assert_eq!(
+            auto_repeat.tick(start + DAS).len(),
+            INSTANT_REPEATS as usize
+        );
+        assert!(auto_repeat.tick(start + DAS * 2).is_empty());
+    }
+
+    #[test]
+    fn test_fallback_tap_does_not_repeat() {
+        let start = Instant::now();
+        let mut auto_repeat = auto_repeat();
+
+        assert!(auto_repeat.press(Key::Left, start));
+        assert!(auto_repeat.tick(start + millis(300)).is_empty());
+        assert!(auto_repeat.press(Key::Left, start + millis(300)));
+    }
+
+    #[test]
+    fn test_fallback_hold_from_terminal_repeats() {
+        let start = Instant::now();
+        let mut auto_repeat = auto_repeat();
+        auto_repeat.press(Key::Left, start);
+        // The terminal starts repeating after its own delay.
+        auto_repeat.press(Key::Left, start + millis(300));
+        auto_repeat.press(Key::Left, start + millis(330));
+
+        assert!(auto_repeat.is_pressed(Key::Left, start + millis(330)));
+        assert!(auto_repeat.tick(start + millis(340)).is_empty());
+        assert!(!auto_repeat.press(Key::Left, start + millis(360)));
+        assert!(auto_repeat.tick(start + millis(360)).is_empty());
+        auto_repeat.press(Key::Left, start + millis(390));
+        assert_eq!(auto_repeat.tick(start + millis(400)), vec![Key::Left]);
+    }
+
+    #[test]
+    fn test_fallback_double_tap() {
+        let start = Instant::now();
+        let mut auto_repeat = auto_repeat();
+
+        assert!(auto_repeat.press(Key::Left, start));
+        assert!(auto_repeat.press(Key::Left, start + millis(80)));
+    }
+
+    #[test]
+    fn test_fallback_release_when_repeats_stop() {
+        let start = Instant::now();
+        let mut auto_repeat = auto_repeat();
+        auto_repeat.press(Key::Left, start);
+        auto_repeat.press(Key::Left, start + millis(300));
+        auto_repeat.press(Key::Left, start + millis(330));
+
+        auto_repeat.tick(start + millis(500));
+
+        assert!(!auto_repeat.is_pressed(Key::Left, start + millis(500)));
+    }
+}
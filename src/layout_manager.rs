@@ -1,35 +1,48 @@
 use std::collections::HashMap;
 use tui::layout::{Constraint, Direction, Layout, Rect};
 
-use crate::board;
+use crate::board::{DEFAULT_KEYBINDINGS, SECOND_KEYBINDINGS};
 
-const BOARD_WIDTH: u16 = board::COLS * 2;
-const BOARD_HEIGHT: u16 = board::ROWS;
-
-const SCORE_BAR_WIDTH: u16 = board::ROWS;
+const SCORE_BAR_WIDTH: u16 = 20;
 const SCORE_BAR_HEIGHT: u16 = 14;
 
-const KEYS_INFO_WIDTH: u16 = 7;
+const HOLD_BAR_HEIGHT: u16 = 5;
+const NEXT_BAR_HEIGHT: u16 = 17;
 
 pub const FIRST_BOARD_KEY: &str = "first_board";
 pub const FIRST_KEY_INFO_KEY: &str = "first_keys_info";
 pub const FIRST_SCORE_BOARD_KEY: &str = "first_score_board";
+pub const FIRST_HOLD_KEY: &str = "first_hold";
+pub const FIRST_NEXT_KEY: &str = "first_next";
+pub const FIRST_GARBAGE_KEY: &str = "first_garbage";
 pub const SECOND_BOARD_KEY: &str = "second_board";
 pub const SECOND_KEY_INFO_KEY: &str = "second_keys_info";
 pub const SECOND_SCORE_BOARD_KEY: &str = "second_score_board";
-
-pub fn get_layouts(rect: Rect) -> HashMap<String, Rect> {
+pub const SECOND_HOLD_KEY: &str = "second_hold";
+pub const SECOND_NEXT_KEY: &str = "second_next";
+pub const SECOND_GARBAGE_KEY: &str = "second_garbage";
+pub const SINGLE_BOARD_KEY: &str = "single_board";
+pub const SINGLE_KEY_INFO_KEY: &str = "single_keys_info";
+pub const SINGLE_HUD_KEY: &str = "single_hud";
+pub const SINGLE_HOLD_KEY: &str = "single_hold";
+pub const SINGLE_NEXT_KEY: &str = "single_next";
+
+/// Splits the screen for two boards of `cols` by `rows` cells, each cell is
+/// two characters wide.
+pub fn get_layouts(rect: Rect, cols: u16, rows: u16) -> HashMap<String, Rect> {
     let mut layouts: HashMap<String, Rect> = HashMap::new();
+    let board_width = cols * 2;
+    let board_height = rows;
 
     let horizontal_chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints(
             [
-                Constraint::Length(BOARD_HEIGHT),
+                Constraint::Length(board_width),
                 Constraint::Length(5),
                 Constraint::Length(SCORE_BAR_HEIGHT),
                 Constraint::Length(10),
-                Constraint::Length(BOARD_HEIGHT),
+                Constraint::Length(board_width),
                 Constraint::Length(5),
                 Constraint::Length(SCORE_BAR_HEIGHT),
                 Constraint::Min(0),
@@ -38,23 +51,111 @@ pub fn get_layouts(rect: Rect) -> HashMap<String, Rect> {
         )
         .split(rect);
 
-    let first_board = get_vertical(&horizontal_chunks[0], vec![BOARD_WIDTH], 0);
-    let first_keys_info =
-        get_vertical(&horizontal_chunks[0], vec![BOARD_WIDTH, KEYS_INFO_WIDTH], 1);
-    let first_score_board = get_vertical(&horizontal_chunks[2], vec![SCORE_BAR_WIDTH], 0);
-    let second_board = get_vertical(&horizontal_chunks[4], vec![BOARD_WIDTH], 0);
-    let second_keys_info =
-        get_vertical(&horizontal_chunks[4], vec![BOARD_WIDTH, KEYS_INFO_WIDTH], 1);
-    let second_score_board = get_vertical(&horizontal_chunks[6], vec![SCORE_BAR_WIDTH], 0);
+    let first_board = get_vertical(&horizontal_chunks[0], vec![board_height], 0);
+    let first_keys_info = get_vertical(
+        &horizontal_chunks[0],
+        vec![board_height, keys_info_height()],
+        1,
+    );
+    let first_garbage = get_vertical(&horizontal_chunks[1], vec![board_height], 0);
+    let first_hold = get_vertical(&horizontal_chunks[2], vec![HOLD_BAR_HEIGHT], 0);
+    let first_next = get_vertical(
+        &horizontal_chunks[2],
+        vec![HOLD_BAR_HEIGHT, NEXT_BAR_HEIGHT],
+        1,
+    );
+    let first_score_board = get_vertical(
+        &horizontal_chunks[2],
+        vec![HOLD_BAR_HEIGHT, NEXT_BAR_HEIGHT, SCORE_BAR_WIDTH],
+        2,
+    );
+    let second_board = get_vertical(&horizontal_chunks[4], vec![board_height], 0);
+    let second_keys_info = get_vertical(
+        &horizontal_chunks[4],
+        vec![board_height, keys_info_height()],
+        1,
+    );
+    let second_garbage = get_vertical(&horizontal_chunks[5], vec![board_height], 0);
+    let second_hold = get_vertical(&horizontal_chunks[6], vec![HOLD_BAR_HEIGHT], 0);
+    let second_next = get_vertical(
+        &horizontal_chunks[6],
+        vec![HOLD_BAR_HEIGHT, NEXT_BAR_HEIGHT],
+        1,
+    );
+    let second_score_board = get_vertical(
+        &horizontal_chunks[6],
+        vec![HOLD_BAR_HEIGHT, NEXT_BAR_HEIGHT, SCORE_BAR_WIDTH],
+        2,
+    );
 
     layouts.insert(FIRST_BOARD_KEY.to_string(), first_board);
     layouts.insert(FIRST_KEY_INFO_KEY.to_string(), first_keys_info);
     layouts.insert(FIRST_SCORE_BOARD_KEY.to_string(), first_score_board);
+    layouts.insert(FIRST_HOLD_KEY.to_string(), first_hold);
+    layouts.insert(FIRST_NEXT_KEY.to_string(), first_next);
+    layouts.insert(FIRST_GARBAGE_KEY.to_string(), first_garbage);
     layouts.insert(SECOND_BOARD_KEY.to_string(), second_board);
     layouts.insert(SECOND_KEY_INFO_KEY.to_string(), second_keys_info);
     layouts.insert(SECOND_SCORE_BOARD_KEY.to_string(), second_score_board);
+    layouts.insert(SECOND_HOLD_KEY.to_string(), second_hold);
+    layouts.insert(SECOND_NEXT_KEY.to_string(), second_next);
+    layouts.insert(SECOND_GARBAGE_KEY.to_string(), second_garbage);
+
+    layouts
+}
+
+/// Splits the screen for one board of `cols` by `rows` cells in the middle,
+/// with hold on its left, key info below it and the next blocks and HUD on its
+/// right.
+pub fn get_single_layouts(rect: Rect, cols: u16, rows: u16) -> HashMap<String, Rect> {
+    let mut layouts: HashMap<String, Rect> = HashMap::new();
+    let board_width = cols * 2;
+    let board_height = rows;
+    let used_width = board_width + 2 * (SCORE_BAR_HEIGHT + 5);
+    let margin = rect.width.saturating_sub(used_width) / 2;
+
+    let horizontal_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(
+            [
+                Constraint::Length(margin),
+                Constraint::Length(SCORE_BAR_HEIGHT),
+                Constraint::Length(5),
+                Constraint::Length(board_width),
+                Constraint::Length(5),
+                Constraint::Length(SCORE_BAR_HEIGHT),
+                Constraint::Min(0),
+            ]
+            .as_ref(),
+        )
+        .split(rect);
 
-    return layouts;
+    let hold = get_vertical(&horizontal_chunks[1], vec![HOLD_BAR_HEIGHT], 0);
+    let board = get_vertical(&horizontal_chunks[3], vec![board_height], 0);
+    let keys_info = get_vertical(
+        &horizontal_chunks[3],
+        vec![board_height, keys_info_height()],
+        1,
+    );
+    let next = get_vertical(&horizontal_chunks[5], vec![NEXT_BAR_HEIGHT], 0);
+    let hud = get_vertical(
+        &horizontal_chunks[5],
+        vec![NEXT_BAR_HEIGHT, SCORE_BAR_WIDTH],
+        1,
+    );
+
+    layouts.insert(SINGLE_BOARD_KEY.to_string(), board);
+    layouts.insert(SINGLE_KEY_INFO_KEY.to_string(), keys_info);
+    layouts.insert(SINGLE_HUD_KEY.to_string(), hud);
+    layouts.insert(SINGLE_HOLD_KEY.to_string(), hold);
+    layouts.insert(SINGLE_NEXT_KEY.to_string(), next);
+
+    layouts
+}
+
+/// Rows needed to list every binding below the blank first line.
+fn keys_info_height() -> u16 {
+    DEFAULT_KEYBINDINGS.len().max(SECOND_KEYBINDINGS.len()) as u16 + 1
 }
 
 fn get_vertical(chunk: &Rect, sizes: Vec<u16>, chunk_index: usize) -> Rect {
@@ -66,7 +167,7 @@ fn get_vertical(chunk: &Rect, sizes: Vec<u16>, chunk_index: usize) -> Rect {
 
     Layout::default()
         .direction(Direction::Vertical)
-        .constraints(constraints.as_ref())
+        .constraints(&constraints[..])
         .split(*chunk)[chunk_index]
 }
 
@@ -90,8 +191,8 @@ mod tests {
 
     #[test]
     fn test_layout_first_board() {
-        let layouts = get_layouts(TERMINAL_RECT);
-        let first_board = layouts.get(&"first_board".to_string()).unwrap();
+        let layouts = get_layouts(TERMINAL_RECT, 10, 20);
+        let first_board = layouts.get("first_board").unwrap();
 
         assert_eq!(BOARD_RECT.width, first_board.width);
         assert_eq!(BOARD_RECT.height, first_board.height);
@@ -99,10 +200,42 @@ mod tests {
 
     #[test]
     fn test_layout_second_board() {
-        let layouts = get_layouts(TERMINAL_RECT);
-        let second_board = layouts.get(&"second_board".to_string()).unwrap();
+        let layouts = get_layouts(TERMINAL_RECT, 10, 20);
+        let second_board = layouts.get("second_board").unwrap();
 
         assert_eq!(BOARD_RECT.width, second_board.width);
         assert_eq!(BOARD_RECT.height, second_board.height);
     }
+
+    #[test]
+    fn test_layout_narrow_tall_board() {
+        let layouts = get_layouts(TERMINAL_RECT, 4, 40);
+        let first_board = layouts.get("first_board").unwrap();
+        let first_hold = layouts.get("first_hold").unwrap();
+
+        assert_eq!(8, first_board.width);
+        assert_eq!(40, first_board.height);
+        assert_eq!(first_board.right() + 5, first_hold.x);
+    }
+
+    #[test]
+    fn test_layout_single_board_centred() {
+        let layouts = get_single_layouts(TERMINAL_RECT, 10, 20);
+        let board = layouts.get("single_board").unwrap();
+        let hold = layouts.get("single_hold").unwrap();
+        let hud = layouts.get("single_hud").unwrap();
+
+        assert_eq!(BOARD_RECT.width, board.width);
+        assert_eq!(BOARD_RECT.height, board.height);
+        assert_eq!(board.x - hold.x, hud.right() - board.right());
+        assert_eq!(TERMINAL_RECT.width - hud.right(), hold.x);
+    }
+
+    #[test]
+    fn test_layout_keys_info_fits_every_binding() {
+        let layouts = get_layouts(TERMINAL_RECT, 10, 20);
+        let first_keys_info = layouts.get("first_keys_info").unwrap();
+
+        assert_eq!(DEFAULT_KEYBINDINGS.len() as u16 + 1, first_keys_info.height);
+    }
 }
@@ -1,100 +1,452 @@
 mod block;
 mod board;
 mod event;
+mod input;
 mod layout_manager;
+mod sprint;
 mod widgets;
 
-use board::{Board, DEFAULT_KEYBINDINGS, SECOND_KEYBINDINGS};
+use board::attack::AttackTable;
+use board::game_over::GameOverReason;
+use board::garbage::HolePattern;
+use board::randomizer::RandomizerKind;
+use board::{
+    Board, BoardConfig, DEFAULT_KEYBINDINGS, MAX_COLS, MAX_ROWS, MIN_COLS, SECOND_KEYBINDINGS,
+};
 use event::{Config, Event, Events};
-use layout_manager::get_layouts;
+use input::{AutoRepeat, KeyReleases, DEFAULT_ARR, DEFAULT_DAS};
+use layout_manager::{get_layouts, get_single_layouts};
 use layout_manager::{
-    FIRST_BOARD_KEY, FIRST_KEY_INFO_KEY, FIRST_SCORE_BOARD_KEY, SECOND_BOARD_KEY,
-    SECOND_KEY_INFO_KEY, SECOND_SCORE_BOARD_KEY,
+    FIRST_BOARD_KEY, FIRST_GARBAGE_KEY, FIRST_HOLD_KEY, FIRST_KEY_INFO_KEY, FIRST_NEXT_KEY,
+    FIRST_SCORE_BOARD_KEY, SECOND_BOARD_KEY, SECOND_GARBAGE_KEY, SECOND_HOLD_KEY,
+    SECOND_KEY_INFO_KEY, SECOND_NEXT_KEY, SECOND_SCORE_BOARD_KEY, SINGLE_BOARD_KEY,
+    SINGLE_HOLD_KEY, SINGLE_HUD_KEY, SINGLE_KEY_INFO_KEY, SINGLE_NEXT_KEY,
 };
+use sprint::{PreviousBest, Records, SprintResult, DEFAULT_SPRINT_LINES};
 use std::error::Error;
 use std::io;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use termion::{event::Key, raw::IntoRawMode, screen::AlternateScreen};
-use tui::{backend::TermionBackend, Terminal};
-use widgets::{game_over_multiplayer, keys_info, score_bar};
+use tui::backend::{Backend, TermionBackend};
+use tui::{Frame, Terminal};
+use widgets::{
+    game_over_multiplayer, game_over_single_player, garbage_meter, hold_bar, hud, keys_info,
+    next_bar, pause_screen, score_bar, sprint_hud, sprint_results,
+};
 
 #[macro_use]
 extern crate lazy_static;
 
+/// Kind of game to play.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    /// Two players on one keyboard, sending garbage to each other.
+    Versus,
+    /// One player on a centred board.
+    Single,
+    /// One player clearing a number of lines against the clock.
+    Sprint,
+}
+
+impl Mode {
+    fn from_name(name: &str) -> Option<Mode> {
+        match name {
+            "versus" => Some(Mode::Versus),
+            "single" => Some(Mode::Single),
+            "sprint" => Some(Mode::Sprint),
+            _ => None,
+        }
+    }
+}
+
+/// Options of a match read from the command line.
+struct Options {
+    mode: Mode,
+    board_config: BoardConfig,
+    /// Both boards get the same blocks in the same order.
+    shared_pieces: bool,
+    /// Delay before a held move key starts repeating (DAS).
+    das: Duration,
+    /// Time between repeats of a held move key (ARR).
+    arr: Duration,
+}
+
 fn main() -> Result<(), Box<dyn Error>> {
+    let options = parse_options()?;
+
     // Setting up terminal
     let stdout = io::stdout().into_raw_mode()?;
     let stdout = AlternateScreen::from(stdout);
     let backend = TermionBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
+    let key_releases = KeyReleases::enable(Box::new(io::stdout()))?;
 
     let config = Config {
         exit_key: Key::Char('q'),
-        tick_rate: Duration::from_millis(500),
+        tick_rate: Duration::from_millis(20),
     };
 
     let events = Events::with_config(config);
 
-    let mut first_board = Board::new_player(DEFAULT_KEYBINDINGS.clone());
-    let mut second_board = Board::new_player(SECOND_KEYBINDINGS.clone());
+    let (mut first_board, mut second_board) = new_boards(&options);
+
+    let repeat_keys = [&*DEFAULT_KEYBINDINGS, &*SECOND_KEYBINDINGS]
+        .iter()
+        .flat_map(|keys| vec![keys["left"], keys["right"]])
+        .collect();
+    let mut auto_repeat = AutoRepeat::new(options.das, options.arr, repeat_keys);
+
+    let records = Records::in_home();
+    let mut sprint_result = None;
+
+    let mut last_tick = Instant::now();
+    let mut is_paused = false;
 
     loop {
+        let has_game_ended = first_board.has_game_ended()
+            || second_board.as_ref().is_some_and(Board::has_game_ended);
+        if first_board.game_over_reason() == Some(GameOverReason::GoalReached)
+            && sprint_result.is_none()
+        {
+            sprint_result = Some(record_sprint(
+                records.as_ref(),
+                &first_board,
+                last_tick.elapsed(),
+            ));
+        }
+
         terminal.draw(|f| {
-            if !first_board.has_game_ended() && !second_board.has_game_ended() {
-                let layouts = get_layouts(f.size());
-
-                f.render_widget(
-                    first_board.clone(),
-                    *layouts.get(&FIRST_BOARD_KEY.to_string()).unwrap(),
-                );
-                f.render_widget(
-                    keys_info(first_board.keys()),
-                    *layouts.get(&FIRST_KEY_INFO_KEY.to_string()).unwrap(),
-                );
-                f.render_widget(
-                    score_bar(first_board.score()),
-                    *layouts.get(&FIRST_SCORE_BOARD_KEY.to_string()).unwrap(),
-                );
-                f.render_widget(
-                    second_board.clone(),
-                    *layouts.get(&SECOND_BOARD_KEY.to_string()).unwrap(),
-                );
-                f.render_widget(
-                    keys_info(second_board.keys()),
-                    *layouts.get(&SECOND_KEY_INFO_KEY.to_string()).unwrap(),
-                );
-                f.render_widget(
-                    score_bar(second_board.score()),
-                    *layouts.get(&SECOND_SCORE_BOARD_KEY.to_string()).unwrap(),
-                );
-            } else {
-                let screen = game_over_multiplayer(
-                    first_board.has_game_ended(),
-                    second_board.has_game_ended(),
-                );
-                f.render_widget(screen, f.size())
+            if is_paused {
+                // The boards stay hidden so nobody can plan while paused.
+                f.render_widget(pause_screen(), f.size());
+                return;
+            }
+            match &second_board {
+                Some(second_board) if !has_game_ended => draw_versus(f, &first_board, second_board),
+                Some(second_board) => {
+                    let screen = game_over_multiplayer(
+                        first_board.game_over_reason(),
+                        second_board.game_over_reason(),
+                    );
+                    f.render_widget(screen, f.size())
+                }
+                None => match (first_board.game_over_reason(), sprint_result) {
+                    (None, _) => draw_single(f, &first_board),
+                    (Some(GameOverReason::GoalReached), Some(result)) => {
+                        f.render_widget(sprint_results(result), f.size())
+                    }
+                    (Some(reason), _) => {
+                        let screen = game_over_single_player(
+                            reason,
+                            first_board.score(),
+                            first_board.lines(),
+                            first_board.play_time(),
+                        );
+                        f.render_widget(screen, f.size())
+                    }
+                },
             }
         })?;
 
         match events.next()? {
+            // Repeats of held keys come from `auto_repeat` instead.
+            Event::Input(key) if !auto_repeat.press(key, Instant::now()) => (),
             Event::Input(key) => match key {
                 Key::Char('q') => break,
-                Key::Char('r') if first_board.has_game_ended() || second_board.has_game_ended() => {
-                    first_board = Board::new_player(DEFAULT_KEYBINDINGS.clone());
-                    second_board = Board::new_player(SECOND_KEYBINDINGS.clone())
-                }
-                _ => {
-                    first_board.make_action(&key, Some(&mut second_board));
-                    second_board.make_action(&key, Some(&mut first_board));
+                Key::Char('p') if !has_game_ended => is_paused = !is_paused,
+                _ if is_paused => (),
+                Key::Char('r') if has_game_ended => {
+                    let (first, second) = new_boards(&options);
+                    first_board = first;
+                    second_board = second;
+                    sprint_result = None;
                 }
+                // The boards are final once a game ends.
+                _ if has_game_ended => (),
+                _ => make_action(&mut first_board, second_board.as_mut(), &key),
             },
+            Event::Release(key) => auto_repeat.release(key),
+            // Time spent paused is dropped, so gravity, lock delays and clocks
+            // carry on where they stopped.
+            Event::Tick if is_paused => {
+                last_tick = Instant::now();
+                auto_repeat.tick(last_tick);
+            }
             Event::Tick => {
-                first_board.move_down();
-                first_board.tick_count(Some(&mut second_board));
-                second_board.move_down();
-                second_board.tick_count(Some(&mut first_board));
+                let elapsed = last_tick.elapsed();
+                last_tick = Instant::now();
+                for key in auto_repeat.tick(last_tick) {
+                    if has_game_ended {
+                        break;
+                    }
+                    make_action(&mut first_board, second_board.as_mut(), &key);
+                }
+                first_board
+                    .set_soft_drop(auto_repeat.is_pressed(first_board.keys()["down"], last_tick));
+                match second_board.as_mut() {
+                    Some(second_board) => {
+                        second_board.set_soft_drop(
+                            auto_repeat.is_pressed(second_board.keys()["down"], last_tick),
+                        );
+                        first_board.tick(elapsed, Some(second_board));
+                        second_board.tick(elapsed, Some(&mut first_board));
+                    }
+                    None => first_board.tick(elapsed, None),
+                }
             }
         }
     }
+
+    // Leave the alternate screen so the seed stays visible.
+    drop(key_releases);
+    drop(terminal);
+    println!("Seed: {}", first_board.seed());
+
     Ok(())
 }
+
+/// Sends a key to every board, each one ignores keys it isn't bound to.
+fn make_action(first_board: &mut Board, second_board: Option<&mut Board>, key: &Key) {
+    match second_board {
+        Some(second_board) => {
+            first_board.make_action(key, Some(second_board));
+            second_board.make_action(key, Some(first_board));
+        }
+        None => first_board.make_action(key, None),
+    }
+}
+
+fn draw_versus<B: Backend>(f: &mut Frame<B>, first_board: &Board, second_board: &Board) {
+    let layouts = get_layouts(f.size(), first_board.cols(), first_board.rows());
+
+    f.render_widget(first_board.clone(), *layouts.get(FIRST_BOARD_KEY).unwrap());
+    f.render_widget(
+        keys_info(first_board.keys()),
+        *layouts.get(FIRST_KEY_INFO_KEY).unwrap(),
+    );
+    f.render_widget(
+        score_bar(
+            first_board.score(),
+            first_board.level(),
+            first_board.lines(),
+        ),
+        *layouts.get(FIRST_SCORE_BOARD_KEY).unwrap(),
+    );
+    f.render_widget(
+        hold_bar(first_board.held_block(), first_board.can_hold()),
+        *layouts.get(FIRST_HOLD_KEY).unwrap(),
+    );
+    f.render_widget(
+        next_bar(first_board.next_blocks()),
+        *layouts.get(FIRST_NEXT_KEY).unwrap(),
+    );
+    f.render_widget(
+        garbage_meter(first_board.pending_garbage(), first_board.rows()),
+        *layouts.get(FIRST_GARBAGE_KEY).unwrap(),
+    );
+    f.render_widget(
+        second_board.clone(),
+        *layouts.get(SECOND_BOARD_KEY).unwrap(),
+    );
+    f.render_widget(
+        keys_info(second_board.keys()),
+        *layouts.get(SECOND_KEY_INFO_KEY).unwrap(),
+    );
+    f.render_widget(
+        score_bar(
+            second_board.score(),
+            second_board.level(),
+            second_board.lines(),
+        ),
+        *layouts.get(SECOND_SCORE_BOARD_KEY).unwrap(),
+    );
+    f.render_widget(
+        hold_bar(second_board.held_block(), second_board.can_hold()),
+        *layouts.get(SECOND_HOLD_KEY).unwrap(),
+    );
+    f.render_widget(
+        next_bar(second_board.next_blocks()),
+        *layouts.get(SECOND_NEXT_KEY).unwrap(),
+    );
+    f.render_widget(
+        garbage_meter(second_board.pending_garbage(), second_board.rows()),
+        *layouts.get(SECOND_GARBAGE_KEY).unwrap(),
+    );
+}
+
+fn draw_single<B: Backend>(f: &mut Frame<B>, board: &Board) {
+    let layouts = get_single_layouts(f.size(), board.cols(), board.rows());
+
+    f.render_widget(board.clone(), *layouts.get(SINGLE_BOARD_KEY).unwrap());
+    f.render_widget(
+        keys_info(board.keys()),
+        *layouts.get(SINGLE_KEY_INFO_KEY).unwrap(),
+    );
+    let hud = match board.line_goal() {
+        Some(goal) => sprint_hud(board.lines(), goal, board.play_time(), board.pieces()),
+        None => hud(
+            board.score(),
+            board.level(),
+            board.lines(),
+            board.play_time(),
+        ),
+    };
+    f.render_widget(hud, *layouts.get(SINGLE_HUD_KEY).unwrap());
+    f.render_widget(
+        hold_bar(board.held_block(), board.can_hold()),
+        *layouts.get(SINGLE_HOLD_KEY).unwrap(),
+    );
+    f.render_widget(
+        next_bar(board.next_blocks()),
+        *layouts.get(SINGLE_NEXT_KEY).unwrap(),
+    );
+}
+
+/// Compares a finished sprint with the personal best, saving it if it's
+/// better. Without a place to keep records there is nothing to compare with.
+/// The board's clock only moves on ticks, so the time since the last one is
+/// added for goals reached by a key press in between.
+fn record_sprint(records: Option<&Records>, board: &Board, since_tick: Duration) -> SprintResult {
+    let lines = board.lines();
+    let time = board.play_time() + since_tick;
+    let pieces = board.pieces();
+    match records {
+        Some(records) => {
+            let result = records.compare(lines, time, pieces);
+            // The comparison still stands if the new best can't be saved.
+            let _ = records.save(&result);
+            result
+        }
+        None => SprintResult {
+            lines,
+            time,
+            pieces,
+            previous_best: PreviousBest::NoRecords,
+        },
+    }
+}
+
+/// Starts a new game, picking its seed unless one was given. With shared
+/// pieces both boards use the seed, otherwise the second board uses the next
+/// one, so the printed seed replays either way. Single player games have no
+/// second board.
+fn new_boards(options: &Options) -> (Board, Option<Board>) {
+    let seed = options.board_config.seed.unwrap_or_else(rand::random);
+    let first_config = BoardConfig {
+        seed: Some(seed),
+        ..options.board_config
+    };
+    let first_board = Board::with_config(DEFAULT_KEYBINDINGS.clone(), first_config);
+    if options.mode != Mode::Versus {
+        return (first_board, None);
+    }
+
+    let second_seed = if options.shared_pieces {
+        seed
+    } else {
+        seed.wrapping_add(1)
+    };
+    let second_config = BoardConfig {
+        seed: Some(second_seed),
+        ..options.board_config
+    };
+    (
+        first_board,
+        Some(Board::with_config(
+            SECOND_KEYBINDINGS.clone(),
+            second_config,
+        )),
+    )
+}
+
+/// Reads options from the command line, e.g. `--mode=sprint`, `--sprint-lines=20`, `--randomizer=history`,
+/// `--level=5`, `--width=4`, `--height=40`, `--attack=classic`, `--garbage=clean`,
+/// `--seed=42`, `--line-clear-delay=300`, `--entry-delay=100` (milliseconds),
+/// `--das=167`, `--arr=33` (milliseconds), `--soft-drop-factor=20`,
+/// `--shared-pieces` or `--no-ghost`.
+fn parse_options() -> Result<Options, Box<dyn Error>> {
+    let mut mode = Mode::Versus;
+    let mut sprint_lines = DEFAULT_SPRINT_LINES;
+    let mut config = BoardConfig::default();
+    let mut shared_pieces = false;
+    let mut das = DEFAULT_DAS;
+    let mut arr = DEFAULT_ARR;
+
+    for arg in std::env::args().skip(1) {
+        if let Some(name) = arg.strip_prefix("--mode=") {
+            mode = Mode::from_name(name).ok_or(format!("Unknown mode '{}'", name))?;
+        } else if let Some(lines) = arg.strip_prefix("--sprint-lines=") {
+            sprint_lines = match lines.parse() {
+                Ok(lines) if lines >= 1 => lines,
+                _ => return Err(format!("Invalid sprint lines '{}'", lines).into()),
+            };
+        } else if let Some(name) = arg.strip_prefix("--randomizer=") {
+            config.randomizer =
+                RandomizerKind::from_name(name).ok_or(format!("Unknown randomizer '{}'", name))?;
+        } else if let Some(name) = arg.strip_prefix("--attack=") {
+            config.attack_table =
+                AttackTable::from_name(name).ok_or(format!("Unknown attack table '{}'", name))?;
+        } else if let Some(name) = arg.strip_prefix("--garbage=") {
+            config.hole_pattern = HolePattern::from_name(name)
+                .ok_or(format!("Unknown garbage pattern '{}'", name))?;
+        } else if let Some(seed) = arg.strip_prefix("--seed=") {
+            let seed = seed
+                .parse()
+                .map_err(|_| format!("Invalid seed '{}'", seed))?;
+            config.seed = Some(seed);
+        } else if let Some(level) = arg.strip_prefix("--level=") {
+            config.start_level = match level.parse() {
+                Ok(level) if level >= 1 => level,
+                _ => return Err(format!("Invalid level '{}'", level).into()),
+            };
+        } else if let Some(width) = arg.strip_prefix("--width=") {
+            config.cols = match width.parse() {
+                Ok(width) if (MIN_COLS..=MAX_COLS).contains(&width) => width,
+                _ => return Err(format!("Invalid width '{}'", width).into()),
+            };
+        } else if let Some(height) = arg.strip_prefix("--height=") {
+            config.rows = match height.parse() {
+                Ok(height) if (1..=MAX_ROWS).contains(&height) => height,
+                _ => return Err(format!("Invalid height '{}'", height).into()),
+            };
+        } else if let Some(delay) = arg.strip_prefix("--line-clear-delay=") {
+            config.line_clear_delay = parse_millis(delay)?;
+        } else if let Some(delay) = arg.strip_prefix("--entry-delay=") {
+            config.entry_delay = parse_millis(delay)?;
+        } else if let Some(delay) = arg.strip_prefix("--das=") {
+            das = parse_millis(delay)?;
+        } else if let Some(delay) = arg.strip_prefix("--arr=") {
+            arr = parse_millis(delay)?;
+        } else if let Some(factor) = arg.strip_prefix("--soft-drop-factor=") {
+            config.soft_drop_factor = match factor.parse() {
+                Ok(factor) if factor >= 1.0 => factor,
+                _ => return Err(format!("Invalid soft drop factor '{}'", factor).into()),
+            };
+        } else if arg == "--no-ghost" {
+            config.show_ghost = false;
+        } else if arg == "--shared-pieces" {
+            shared_pieces = true;
+        } else {
+            return Err(format!("Unknown argument '{}'", arg).into());
+        }
+    }
+
+    if mode == Mode::Sprint {
+        config.line_goal = Some(sprint_lines);
+    }
+
+    Ok(Options {
+        mode,
+        board_config: config,
+        shared_pieces,
+        das,
+        arr,
+    })
+}
+
+fn parse_millis(millis: &str) -> Result<Duration, Box<dyn Error>> {
+    let millis = millis
+        .parse()
+        .map_err(|_| format!("Invalid delay '{}'", millis))?;
+    Ok(Duration::from_millis(millis))
+}
@@ -0,0 +1,182 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const DEFAULT_SPRINT_LINES: u32 = 40;
+
+// Directory in the home directory where personal bests are kept.
+const RECORDS_DIR: &str = ".tetris-cl";
+
+/// How a finished sprint went.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SprintResult {
+    pub lines: u32,
+    pub time: Duration,
+    pub pieces: u32,
+    pub previous_best: PreviousBest,
+}
+
+/// Best time for the same number of lines before a sprint.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PreviousBest {
+    /// There are no records to compare with.
+    NoRecords,
+    /// No sprint to this many lines was finished before.
+    FirstRun,
+    Time(Duration),
+}
+
+impl SprintResult {
+    /// Whether the sprint beat the previous best, never without records.
+    pub fn is_personal_best(&self) -> bool {
+        match self.previous_best {
+            PreviousBest::NoRecords => false,
+            PreviousBest::FirstRun => true,
+            PreviousBest::Time(best) => self.time < best,
+        }
+    }
+
+    pub fn pieces_per_second(&self) -> f64 {
+        pieces_per_second(self.pieces, self.time)
+    }
+}
+
+pub fn pieces_per_second(pieces: u32, time: Duration) -> f64 {
+    if time == Duration::default() {
+        return 0.0;
+    }
+    pieces as f64 / time.as_secs_f64()
+}
+
+/// Personal best sprint times, one file of milliseconds per line target.
+#[derive(Debug, Clone)]
+pub struct Records {
+    dir: PathBuf,
+}
+
+impl Records {
+    pub fn new(dir: PathBuf) -> Records {
+        Records { dir }
+    }
+
+    /// Records kept in the user's home directory, if it is known.
+    pub fn in_home() -> Option<Records> {
+        let home = std::env::var_os("HOME")?;
+        Some(Records::new(PathBuf::from(home).join(RECORDS_DIR)))
+    }
+
+    pub fn best_time(&self, lines: u32) -> Option<Duration> {
+        let millis = fs::read_to_string(self.path(lines)).ok()?;
+        millis.trim().parse().ok().map(Duration::from_millis)
+    }
+
+    /// Compares a sprint with the best time for the same number of lines.
+    pub fn compare(&self, lines: u32, time: Duration, pieces: u32) -> SprintResult {
+        SprintResult {
+            lines,
+            time,
+            pieces,
+            previous_best: match self.best_time(lines) {
+                Some(best) => PreviousBest::Time(best),
+                None => PreviousBest::FirstRun,
+            },
+        }
+    }
+
+    /// Saves the sprint's time if it is a new personal best.
+    pub fn save(&self, result: &SprintResult) -> io::Result<()> {
+        if result.is_personal_best() {
+            fs::create_dir_all(&self.dir)?;
+            fs::write(
+                self.path(result.lines),
+                format!("{}\n", result.time.as_millis()),
+            )?;
+        }
+        Ok(())
+    }
+
+    fn path(&self, lines: u32) -> PathBuf {
+        self.dir.join(format!("sprint-{}", lines))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Records in a temporary directory that is removed when dropped.
+    struct TempRecords(Records);
+
+    impl TempRecords {
+        fn new(name: &str) -> TempRecords {
+            let dir =
+                std::env::temp_dir().join(format!("tetris-cl-{}-{}", name, std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            TempRecords(Records::new(dir))
+        }
+
+        fn record(&self, lines: u32, time: Duration) -> SprintResult {
+            let result = self.0.compare(lines, time, 100);
+            self.0.save(&result).unwrap();
+            result
+        }
+    }
+
+    impl Drop for TempRecords {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0.dir);
+        }
+    }
+
+    #[test]
+    fn test_first_sprint_is_personal_best() {
+        let records = TempRecords::new("first");
+
+        let result = records.record(40, Duration::from_secs(60));
+
+        assert!(result.is_personal_best());
+        assert_eq!(records.0.best_time(40), Some(Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn test_slower_sprint_keeps_best() {
+        let records = TempRecords::new("slower");
+        records.record(40, Duration::from_secs(60));
+
+        let result = records.record(40, Duration::from_secs(70));
+
+        assert!(!result.is_personal_best());
+        assert_eq!(
+            result.previous_best,
+            PreviousBest::Time(Duration::from_secs(60))
+        );
+        assert_eq!(records.0.best_time(40), Some(Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn test_bests_are_kept_per_line_target() {
+        let records = TempRecords::new("targets");
+        records.record(40, Duration::from_secs(60));
+
+        assert_eq!(records.0.best_time(20), None);
+    }
+
+    #[test]
+    fn test_no_records_is_not_personal_best() {
+        let result = SprintResult {
+            lines: 40,
+            time: Duration::from_secs(60),
+            pieces: 100,
+            previous_best: PreviousBest::NoRecords,
+        };
+
+        assert!(!result.is_personal_best());
+    }
+
+    #[test]
+    fn test_pieces_per_second() {
+        assert_eq!(pieces_per_second(100, Duration::from_secs(40)), 2.5);
+        assert_eq!(pieces_per_second(0, Duration::default()), 0.0);
+    }
+}
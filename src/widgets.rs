@@ -1,14 +1,29 @@
 use std::collections::HashMap;
+use std::time::Duration;
 use termion::event::Key;
+
+use crate::block::Block as TetrisBlock;
+use crate::board::game_over::GameOverReason;
+use crate::sprint::{pieces_per_second, PreviousBest, SprintResult};
 use tui::layout::Alignment;
 use tui::style::{Color, Style};
 use tui::text::{Span, Spans};
 use tui::widgets::{Block, Borders, Paragraph, Wrap};
 
-#[allow(dead_code)]
-pub fn game_over_single_player() -> Paragraph<'static> {
+pub fn game_over_single_player(
+    reason: GameOverReason,
+    score: u32,
+    lines: u32,
+    time: Duration,
+) -> Paragraph<'static> {
     let text = vec![
         Spans::from(Span::styled("Game Over", Style::default().fg(Color::Red))),
+        Spans::from(reason.message()),
+        Spans::from(""),
+        Spans::from(format!("Score: {}", score)),
+        Spans::from(format!("Lines: {}", lines)),
+        Spans::from(format!("Time: {}", format_time(time))),
+        Spans::from(""),
         Spans::from("Press 'q' to quit."),
         Spans::from("Press 'r' to restart."),
     ];
@@ -18,8 +33,8 @@ pub fn game_over_single_player() -> Paragraph<'static> {
 }
 
 pub fn game_over_multiplayer(
-    has_game_ended_first: bool,
-    has_game_ended_second: bool,
+    first_reason: Option<GameOverReason>,
+    second_reason: Option<GameOverReason>,
 ) -> Paragraph<'static> {
     let first_player_wins = Span::styled("First Player Wins!", Style::default().fg(Color::Cyan));
     let second_player_wins =
@@ -35,12 +50,20 @@ pub fn game_over_multiplayer(
         Spans::from(Span::styled("Game Over", Style::default().fg(Color::Red))),
         Spans::from(""),
     ];
-    if has_game_ended_first && has_game_ended_second {
-        text.push(Spans::from(equals));
-    } else if has_game_ended_first {
-        text.push(Spans::from(second_player_wins));
-    } else {
-        text.push(Spans::from(first_player_wins));
+    match (first_reason, second_reason) {
+        (Some(_), Some(_)) => text.push(Spans::from(equals)),
+        (Some(_), None) => text.push(Spans::from(second_player_wins)),
+        _ => text.push(Spans::from(first_player_wins)),
+    }
+
+    text.push(Spans::from(""));
+    for (player, reason) in [
+        ("First player", first_reason),
+        ("Second player", second_reason),
+    ] {
+        if let Some(reason) = reason {
+            text.push(Spans::from(format!("{}: {}", player, reason.message())));
+        }
     }
 
     text.push(Spans::from(""));
@@ -51,21 +74,147 @@ pub fn game_over_multiplayer(
         .wrap(Wrap { trim: true })
 }
 
-pub fn score_bar(score: u32) -> Paragraph<'static> {
+pub fn pause_screen() -> Paragraph<'static> {
     let text = vec![
-        Spans::from("Your score is:"),
+        Spans::from(Span::styled("Paused", Style::default().fg(Color::Yellow))),
         Spans::from(""),
-        Spans::from(Span::styled(
-            format!("{}", score),
+        Spans::from("Press 'p' to resume."),
+        Spans::from("Press 'q' to quit."),
+    ];
+    Paragraph::new(text)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+}
+
+pub fn score_bar(score: u32, level: u32, lines: u32) -> Paragraph<'static> {
+    stats_bar(score_lines(score, level, lines))
+}
+
+/// Score bar of a single player game, with the time played.
+pub fn hud(score: u32, level: u32, lines: u32, time: Duration) -> Paragraph<'static> {
+    let mut text = score_lines(score, level, lines);
+    text.push(Spans::from(""));
+    text.push(Spans::from("Time:"));
+    text.push(Spans::from(""));
+    text.push(Spans::from(Span::styled(
+        format_time(time),
+        Style::default().fg(Color::Red),
+    )));
+    stats_bar(text)
+}
+
+/// Score bar of a sprint, with the lines left and the pace.
+pub fn sprint_hud(lines: u32, goal: u32, time: Duration, pieces: u32) -> Paragraph<'static> {
+    let stats = [
+        ("Lines:", format!("{}/{}", lines, goal)),
+        ("Time:", format_time(time)),
+        ("PPS:", format!("{:.2}", pieces_per_second(pieces, time))),
+    ];
+    let mut text = vec![];
+    for (label, value) in stats {
+        if !text.is_empty() {
+            text.push(Spans::from(""));
+        }
+        text.push(Spans::from(label));
+        text.push(Spans::from(""));
+        text.push(Spans::from(Span::styled(
+            value,
             Style::default().fg(Color::Red),
+        )));
+    }
+    stats_bar(text)
+}
+
+pub fn sprint_results(result: SprintResult) -> Paragraph<'static> {
+    let mut text = vec![
+        Spans::from(Span::styled(
+            "Sprint Complete",
+            Style::default().fg(Color::Green),
+        )),
+        Spans::from(""),
+        Spans::from(format!(
+            "{} lines in {}",
+            result.lines,
+            format_time(result.time)
+        )),
+        Spans::from(format!(
+            "{} pieces, {:.2} per second",
+            result.pieces,
+            result.pieces_per_second()
         )),
+        Spans::from(""),
     ];
+    if result.is_personal_best() {
+        text.push(Spans::from(Span::styled(
+            "New personal best!",
+            Style::default().fg(Color::Yellow),
+        )));
+    }
+    if let PreviousBest::Time(best) = result.previous_best {
+        let (sign, difference) = if result.time < best {
+            ('-', best - result.time)
+        } else {
+            ('+', result.time - best)
+        };
+        text.push(Spans::from(format!(
+            "Previous best: {} ({}{})",
+            format_time(best),
+            sign,
+            format_time(difference)
+        )));
+    }
+    text.push(Spans::from(""));
+    text.push(Spans::from("Press 'q' to quit."));
+    text.push(Spans::from("Press 'r' to restart."));
+
+    Paragraph::new(text)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+}
+
+/// Formats a time as minutes, seconds and milliseconds, e.g. `1:05.250`.
+pub fn format_time(time: Duration) -> String {
+    let millis = time.as_millis();
+    format!(
+        "{}:{:02}.{:03}",
+        millis / 60_000,
+        millis / 1000 % 60,
+        millis % 1000
+    )
+}
+
+fn stats_bar(text: Vec<Spans<'static>>) -> Paragraph<'static> {
     Paragraph::new(text)
         .block(Block::default().borders(Borders::ALL))
         .alignment(Alignment::Center)
         .wrap(Wrap { trim: true })
 }
 
+fn score_lines(score: u32, level: u32, lines: u32) -> Vec<Spans<'static>> {
+    vec![
+        Spans::from("Your score is:"),
+        Spans::from(""),
+        Spans::from(Span::styled(
+            format!("{}", score),
+            Style::default().fg(Color::Red),
+        )),
+        Spans::from(""),
+        Spans::from("Level:"),
+        Spans::from(""),
+        Spans::from(Span::styled(
+            format!("{}", level),
+            Style::default().fg(Color::Red),
+        )),
+        Spans::from(""),
+        Spans::from("Lines:"),
+        Spans::from(""),
+        Spans::from(Span::styled(
+            format!("{}", lines),
+            Style::default().fg(Color::Red),
+        )),
+    ]
+}
+
 pub fn keys_info(keys: HashMap<String, Key>) -> Paragraph<'static> {
     let mut text = vec![Spans::from("")];
 
@@ -80,3 +229,73 @@ pub fn keys_info(keys: HashMap<String, Key>) -> Paragraph<'static> {
         .alignment(Alignment::Center)
         .wrap(Wrap { trim: true })
 }
+
+pub fn hold_bar(block: Option<TetrisBlock>, can_hold: bool) -> Paragraph<'static> {
+    let mut text = vec![Spans::from("")];
+
+    if let Some(block) = block {
+        let color = if can_hold {
+            block.color()
+        } else {
+            Color::DarkGray
+        };
+        text.append(&mut block_lines(&block, color));
+    }
+
+    Paragraph::new(text)
+        .block(Block::default().borders(Borders::ALL).title("Hold"))
+        .alignment(Alignment::Center)
+}
+
+pub fn next_bar(blocks: Vec<TetrisBlock>) -> Paragraph<'static> {
+    let mut text = vec![];
+
+    for block in blocks {
+        text.push(Spans::from(""));
+        text.append(&mut block_lines(&block, block.color()));
+    }
+
+    Paragraph::new(text)
+        .block(Block::default().borders(Borders::ALL).title("Next"))
+        .alignment(Alignment::Center)
+}
+
+/// Column of `height` rows filled from the bottom with one cell per pending
+/// garbage line.
+pub fn garbage_meter(pending: usize, height: u16) -> Paragraph<'static> {
+    let mut text = vec![];
+
+    for row in 0..height as usize {
+        if row + pending >= height as usize {
+            text.push(Spans::from(Span::styled(
+                "  ",
+                Style::default().bg(Color::Red),
+            )));
+        } else {
+            text.push(Spans::from(""));
+        }
+    }
+
+    Paragraph::new(text)
+}
+
+/// Draws the block's current rotation, two characters per cell like the board.
+fn block_lines(block: &TetrisBlock, color: Color) -> Vec<Spans<'static>> {
+    let shape = block.shape();
+    let width = shape.iter().map(|cell| cell.x).max().unwrap_or(0) + 1;
+    let height = shape.iter().map(|cell| cell.y).max().unwrap_or(0) + 1;
+
+    let mut lines = vec![];
+    for y in 0..height {
+        let mut spans = vec![];
+        for x in 0..width {
+            if shape.iter().any(|cell| cell.x == x && cell.y == y) {
+                spans.push(Span::styled("  ", Style::default().bg(color)));
+            } else {
+                spans.push(Span::raw("  "));
+            }
+        }
+        lines.push(Spans::from(spans));
+    }
+    lines
+}